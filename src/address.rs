@@ -0,0 +1,97 @@
+/// Every 7-bit I2C address that a MAX30205 device can be strapped to via its A0, A1, and A2 pins.
+///
+/// This is the usual address list to pass to [`MAX30205::scan`](crate::MAX30205::scan).
+/// The addresses for the most common straps (0x49 and 0x48, with A1 and A2 grounded) come first.
+pub const ADDRESSES: [u8; 32] = [
+    0x49, 0x48, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45,
+    0x46, 0x47, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f,
+    0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x5a, 0x5b, 0x5c, 0x5d, 0x5e, 0x5f,
+];
+
+/// The SMBus Alert Response Address (ARA), which devices with an asserted alert respond to with their own address.
+/// See [`MAX30205::alert_response`](crate::MAX30205::alert_response).
+pub const ALERT_RESPONSE_ADDRESS: u8 = 0x0c;
+
+/// The connection of one of the A0, A1, or A2 address select pins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Pin {
+    /// The pin is tied to ground.
+    Gnd,
+    /// The pin is tied to the supply voltage.
+    Vdd,
+    /// The pin is tied to the I2C clock line.
+    Scl,
+    /// The pin is tied to the I2C data line.
+    Sda,
+}
+
+/// A valid 7-bit I2C address of a MAX30205 device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Address(u8);
+impl Address {
+    /// Validates a raw 7-bit address, returning `None` if it is not one of the [`ADDRESSES`] a MAX30205 can be strapped to.
+    pub const fn new(addr: u8) -> Option<Self> {
+        let mut i = 0;
+        while i < ADDRESSES.len() {
+            if ADDRESSES[i] == addr { return Some(Address(addr)) }
+            i += 1;
+        }
+        None
+    }
+    /// Computes the address selected by the given A2, A1, and A0 pin connections,
+    /// or `None` if the combination is not a valid strap (A0 only supports [`Pin::Gnd`] and [`Pin::Vdd`]).
+    pub const fn from_pins(a2: Pin, a1: Pin, a0: Pin) -> Option<Self> {
+        let a0 = match a0 {
+            Pin::Gnd => 0b0,
+            Pin::Vdd => 0b1,
+            Pin::Scl | Pin::Sda => return None,
+        };
+        let a1 = match a1 {
+            Pin::Gnd => 0b00,
+            Pin::Vdd => 0b01,
+            Pin::Scl => 0b10,
+            Pin::Sda => 0b11,
+        };
+        let a2 = match a2 {
+            Pin::Gnd => 0b01,
+            Pin::Vdd => 0b00,
+            Pin::Scl => 0b10,
+            Pin::Sda => 0b11,
+        };
+        Some(Address(0x40 | (a2 << 3) | (a1 << 1) | a0))
+    }
+    /// Gets the raw 7-bit address, as used by [`MAX30205::new`](crate::MAX30205::new).
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+}
+impl From<Address> for u8 {
+    fn from(addr: Address) -> Self {
+        addr.0
+    }
+}
+
+/// The storage of a device address in a driver, which is either chosen at runtime (`u8`)
+/// or fixed at compile time ([`ConstAddress`]).
+pub trait AddressSource: Copy {
+    /// Gets the raw 7-bit address.
+    fn get(self) -> u8;
+}
+impl AddressSource for u8 {
+    fn get(self) -> u8 {
+        self
+    }
+}
+
+/// An address fixed at compile time, which takes no space in the driver. See [`MAX30205Const`](crate::MAX30205Const).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ConstAddress<const ADDR: u8>;
+impl<const ADDR: u8> AddressSource for ConstAddress<ADDR> {
+    fn get(self) -> u8 {
+        ADDR
+    }
+}
@@ -0,0 +1,115 @@
+use embedded_hal::digital::{Error as _, InputPin};
+use embedded_hal::i2c::I2c;
+
+use crate::{MAX30205, Polarity, Error};
+#[cfg(feature = "async")]
+use crate::MAX30205Async;
+
+/// A blocking sensor bundled with the input pin connected to its OS output,
+/// for the common design of waking the MCU (or raising an interrupt) on overtemperature.
+///
+/// The alert is configured through the sensor as usual. A typical setup asserts the OS output above 38 °C
+/// until the temperature falls back below 37.5 °C, requiring several consecutive readings to filter noise:
+///
+/// ```ignore
+/// let mut sensor = MAX30205::new(addr, i2c)?;
+/// sensor.set_tos(38.0)?;
+/// sensor.set_thyst(37.5)?;
+/// sensor.set_fault_queue(FaultQueue::Four)?;
+/// sensor.set_os_mode(OsMode::Comparator)?; // or OsMode::Interrupt to assert once per crossing
+/// let mut alert = InterruptDriver::new(sensor, os_pin)?;
+/// // configure os_pin as an MCU wake-up/interrupt source, then in the handler:
+/// if alert.alert_asserted()? {
+///     let temp = alert.sensor().get_temperature()?;
+/// }
+/// ```
+///
+/// In [`OsMode::Interrupt`](crate::OsMode::Interrupt), the output stays asserted until any register is read,
+/// so the handler must access the sensor to rearm it.
+#[cfg_attr(feature = "async", doc = "See [`AlertDriver`](crate::AlertDriver) for an async equivalent.")]
+pub struct InterruptDriver<I2C, P> {
+    sensor: MAX30205<I2C>,
+    os: P,
+    polarity: Polarity,
+}
+impl<I2C: I2c, P: InputPin> InterruptDriver<I2C, P> {
+    /// Bundles a sensor with the pin connected to its OS output.
+    /// This reads the configured [`Polarity`] from the device, so later polarity changes should be made through [`InterruptDriver::set_os_polarity`].
+    pub fn new(mut sensor: MAX30205<I2C>, os: P) -> Result<Self, Error<I2C::Error>> {
+        let polarity = sensor.os_polarity()?;
+        Ok(Self { sensor, os, polarity })
+    }
+    /// Sets the active level of the OS output, keeping the driver in sync. See [`MAX30205::set_os_polarity`] for details.
+    pub fn set_os_polarity(&mut self, polarity: Polarity) -> Result<(), Error<I2C::Error>> {
+        self.sensor.set_os_polarity(polarity)?;
+        self.polarity = polarity;
+        Ok(())
+    }
+    /// Checks whether the OS output is currently asserted. This only reads the pin, so it does not clear an interrupt mode alert.
+    /// Pin failures are reported as [`Error::Pin`].
+    pub fn alert_asserted(&mut self) -> Result<bool, Error<I2C::Error>> {
+        match self.polarity {
+            Polarity::ActiveLow => self.os.is_low(),
+            Polarity::ActiveHigh => self.os.is_high(),
+        }.map_err(|e| Error::Pin(e.kind()))
+    }
+}
+impl<I2C, P> InterruptDriver<I2C, P> {
+    /// Gets the bundled sensor, e.g., to change its thresholds.
+    pub fn sensor(&mut self) -> &mut MAX30205<I2C> {
+        &mut self.sensor
+    }
+    /// Gets the bundled sensor and pin back.
+    pub fn into_inner(self) -> (MAX30205<I2C>, P) {
+        (self.sensor, self.os)
+    }
+}
+
+/// An async sensor bundled with the input pin connected to its OS output, for event-driven alert handling.
+///
+/// The alert behavior is controlled by the sensor configuration as usual (see [`MAX30205Async::set_tos`],
+/// [`MAX30205Async::set_thyst`], and [`MAX30205Async::set_os_mode`]). In particular, in [`OsMode::Interrupt`](crate::OsMode::Interrupt)
+/// the alert is cleared by the register read performed by [`AlertDriver::wait_for_alert`], so each call waits for a new event,
+/// whereas in [`OsMode::Comparator`](crate::OsMode::Comparator) the call returns immediately for as long as the temperature remains above THYST.
+/// See [`InterruptDriver`] for setup details and a blocking equivalent.
+#[cfg(feature = "async")]
+pub struct AlertDriver<I2C, P> {
+    sensor: MAX30205Async<I2C>,
+    os: P,
+    polarity: Polarity,
+}
+#[cfg(feature = "async")]
+impl<I2C: embedded_hal_async::i2c::I2c, P: embedded_hal_async::digital::Wait> AlertDriver<I2C, P> {
+    /// Bundles a sensor with the pin connected to its OS output.
+    /// This reads the configured [`Polarity`] from the device, so later polarity changes should be made through [`AlertDriver::set_os_polarity`].
+    pub async fn new(mut sensor: MAX30205Async<I2C>, os: P) -> Result<Self, Error<I2C::Error>> {
+        let polarity = sensor.os_polarity().await?;
+        Ok(Self { sensor, os, polarity })
+    }
+    /// Sets the active level of the OS output, keeping the driver in sync. See [`MAX30205Async::set_os_polarity`] for details.
+    pub async fn set_os_polarity(&mut self, polarity: Polarity) -> Result<(), Error<I2C::Error>> {
+        self.sensor.set_os_polarity(polarity).await?;
+        self.polarity = polarity;
+        Ok(())
+    }
+    /// Suspends until the OS output is asserted, then returns the temperature reading that triggered it.
+    /// Pin failures are reported as [`Error::Pin`].
+    pub async fn wait_for_alert(&mut self) -> Result<crate::Temperature, Error<I2C::Error>> {
+        match self.polarity {
+            Polarity::ActiveLow => self.os.wait_for_low().await,
+            Polarity::ActiveHigh => self.os.wait_for_high().await,
+        }.map_err(|e| Error::Pin(e.kind()))?;
+        self.sensor.get_temperature().await
+    }
+}
+#[cfg(feature = "async")]
+impl<I2C, P> AlertDriver<I2C, P> {
+    /// Gets the bundled sensor, e.g., to change its thresholds.
+    pub fn sensor(&mut self) -> &mut MAX30205Async<I2C> {
+        &mut self.sensor
+    }
+    /// Gets the bundled sensor and pin back.
+    pub fn into_inner(self) -> (MAX30205Async<I2C>, P) {
+        (self.sensor, self.os)
+    }
+}
@@ -0,0 +1,68 @@
+use embedded_hal::i2c::I2c;
+
+use crate::{MAX30205, Config, Thresholds, Temperature, Error};
+
+/// A collection of MAX30205 sensors sharing a single I2C bus.
+///
+/// Each sensor keeps its own driver state (e.g., data format), and is accessed through the one owned bus handle.
+/// Operations on all sensors report errors per sensor, so one faulty sensor does not hide the others.
+pub struct MAX30205Array<I2C, const N: usize> {
+    i2c: I2C,
+    sensors: [MAX30205<()>; N],
+}
+impl<I2C: I2c, const N: usize> MAX30205Array<I2C, N> {
+    /// Constructs an array of sensors at the given addresses without any bus communication.
+    /// Use [`MAX30205Array::init_all`] to initialize the devices, or skip it if they are already configured.
+    /// See [`MAX30205::attach`] for details.
+    pub fn attach(i2c: I2C, addresses: [u8; N]) -> Self {
+        Self { i2c, sensors: addresses.map(|addr| MAX30205::attach(addr, ())) }
+    }
+    /// Gets the addresses of the sensors, in order.
+    pub fn addresses(&self) -> [u8; N] {
+        core::array::from_fn(|i| self.sensors[i].address())
+    }
+
+    /// Performs an SMBus alert response read and returns the index of the responding sensor, if any.
+    /// A device that responds but is not part of this array is reported as [`Error::InvalidData`].
+    /// See [`MAX30205::alert_response`] for details.
+    pub fn alert_response(&mut self) -> Result<Option<usize>, Error<I2C::Error>> {
+        match MAX30205::alert_response(&mut self.i2c)? {
+            Some(addr) => self.sensors.iter().position(|s| s.address() == addr).map(Some).ok_or(Error::InvalidData),
+            None => Ok(None),
+        }
+    }
+
+    /// Runs an arbitrary operation on the sensor with the given index.
+    /// This gives access to the full [`MAX30205`] API for a single sensor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`.
+    pub fn with_sensor<R>(&mut self, index: usize, f: impl FnOnce(&mut MAX30205<&mut I2C>) -> R) -> R {
+        let mut dev = self.sensors[index].rebind(&mut self.i2c);
+        let res = f(&mut dev);
+        self.sensors[index] = dev.rebind(());
+        res
+    }
+    /// Runs an operation on every sensor (in order), returning the per-sensor results.
+    pub fn for_each<R>(&mut self, mut f: impl FnMut(&mut MAX30205<&mut I2C>) -> R) -> [R; N] {
+        core::array::from_fn(|i| self.with_sensor(i, &mut f))
+    }
+
+    /// Initializes every sensor as in [`MAX30205::new`], returning the per-sensor results.
+    pub fn init_all(&mut self) -> [Result<(), Error<I2C::Error>>; N] {
+        self.configure_all(Config::default(), Thresholds { thyst: 0.0, tos: 0.0 })
+    }
+    /// Writes the given configuration and thresholds to every sensor. See [`MAX30205::new_with_config`] for details.
+    pub fn configure_all(&mut self, config: Config, thresholds: Thresholds) -> [Result<(), Error<I2C::Error>>; N] {
+        self.for_each(|dev| {
+            dev.set_config(config)?;
+            dev.set_thyst(thresholds.thyst)?;
+            dev.set_tos(thresholds.tos)
+        })
+    }
+    /// Gets an instantaneous temperature reading from every sensor. See [`MAX30205::get_temperature`] for details.
+    pub fn read_all(&mut self) -> [Result<Temperature, Error<I2C::Error>>; N] {
+        self.for_each(|dev| dev.get_temperature())
+    }
+}
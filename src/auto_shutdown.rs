@@ -0,0 +1,53 @@
+use core::ops::{Deref, DerefMut};
+
+use embedded_hal::i2c::I2c;
+
+use crate::{MAX30205, Error};
+
+/// A MAX30205 sensor wrapper that puts the device into power saving mode when dropped,
+/// so that a short-lived measurement scope cannot leave it converting continuously.
+///
+/// ```ignore
+/// {
+///     let mut sensor = MAX30205AutoShutdown::new(MAX30205::new(0x48, &mut i2c)?);
+///     let temp = sensor.get_temperature()?;
+/// } // the device is powered down here
+/// ```
+///
+/// Errors while powering down on drop are ignored; use [`MAX30205AutoShutdown::release`] to observe them.
+/// The full [`MAX30205`] API is available through [`Deref`].
+pub struct MAX30205AutoShutdown<I2C: I2c> {
+    inner: Option<MAX30205<I2C>>, // only None while being dismantled
+}
+impl<I2C: I2c> MAX30205AutoShutdown<I2C> {
+    /// Wraps an existing driver.
+    pub fn new(inner: MAX30205<I2C>) -> Self {
+        Self { inner: Some(inner) }
+    }
+    /// Gets the underlying driver back without powering down the device.
+    pub fn into_inner(mut self) -> MAX30205<I2C> {
+        self.inner.take().unwrap()
+    }
+    /// Powers down the device, then gets the bus back. See [`MAX30205::power_down_and_release`] for details.
+    pub fn release(self) -> Result<I2C, (MAX30205<I2C>, Error<I2C::Error>)> {
+        self.into_inner().power_down_and_release()
+    }
+}
+impl<I2C: I2c> Deref for MAX30205AutoShutdown<I2C> {
+    type Target = MAX30205<I2C>;
+    fn deref(&self) -> &Self::Target {
+        self.inner.as_ref().unwrap()
+    }
+}
+impl<I2C: I2c> DerefMut for MAX30205AutoShutdown<I2C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner.as_mut().unwrap()
+    }
+}
+impl<I2C: I2c> Drop for MAX30205AutoShutdown<I2C> {
+    fn drop(&mut self) {
+        if let Some(inner) = &mut self.inner {
+            let _ = inner.power_down();
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! A command line tool for checking the wiring and configuration of a MAX30205 from a Linux host (e.g., a Raspberry Pi).
+
+use std::process::ExitCode;
+
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::I2c;
+use linux_embedded_hal::{Delay, I2cdev};
+use max30205::{MAX30205, ADDRESSES};
+
+const USAGE: &str = "\
+usage: max30205 [--bus <path>] [--addr <addr>] <command>
+
+options:
+    --bus <path>                        the I2C bus device (default: /dev/i2c-1)
+    --addr <addr>                       the device address, e.g., 0x48 (default: the first device found)
+
+commands:
+    scan                                list the addresses of all responding devices
+    read                                print a single temperature reading
+    monitor [--interval <ms>]           print a temperature reading periodically (default: every 1000 ms)
+    config [--tos <c>] [--thyst <c>]    print the configuration and thresholds, optionally setting the thresholds first";
+
+struct Args {
+    bus: String,
+    addr: Option<u8>,
+    command: String,
+    interval_ms: u32,
+    tos: Option<f64>,
+    thyst: Option<f64>,
+}
+
+fn parse_addr(s: &str) -> Option<u8> {
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u8::from_str_radix(hex, 16).ok(),
+        None => s.parse().ok(),
+    }
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args { bus: "/dev/i2c-1".into(), addr: None, command: String::new(), interval_ms: 1000, tos: None, thyst: None };
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("missing value for {arg}"));
+        match arg.as_str() {
+            "--bus" => args.bus = value()?,
+            "--addr" => args.addr = Some(value().and_then(|v| parse_addr(&v).ok_or_else(|| format!("invalid address: {v}")))?),
+            "--interval" => args.interval_ms = value().and_then(|v| v.parse().map_err(|_| format!("invalid interval: {v}")))?,
+            "--tos" => args.tos = Some(value().and_then(|v| v.parse().map_err(|_| format!("invalid threshold: {v}")))?),
+            "--thyst" => args.thyst = Some(value().and_then(|v| v.parse().map_err(|_| format!("invalid threshold: {v}")))?),
+            "-h" | "--help" => return Err(String::new()),
+            _ if args.command.is_empty() && !arg.starts_with('-') => args.command = arg,
+            _ => return Err(format!("unexpected argument: {arg}")),
+        }
+    }
+    if args.command.is_empty() { return Err(String::new()) }
+    Ok(args)
+}
+
+// Checks for a device with a one-byte read (like `i2cdetect -r`), treating any error as an absent device.
+// The driver's scan only skips NACKs, but some Linux bus drivers (e.g., bcm2835 on the Raspberry Pi) report a NACK as EREMOTEIO,
+// which linux-embedded-hal maps to `ErrorKind::Other`, so the scan would fail at the first empty address.
+// Reading just returns the register pointed to, so this leaves the device untouched.
+fn probe(i2c: &mut I2cdev, addr: u8) -> bool {
+    i2c.read(addr, &mut [0]).is_ok()
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let mut i2c = I2cdev::new(&args.bus).map_err(|e| format!("failed to open {}: {e}", args.bus))?;
+
+    if args.command == "scan" {
+        let found: Vec<u8> = ADDRESSES.into_iter().filter(|&addr| probe(&mut i2c, addr)).collect();
+        if found.is_empty() { return Err("no device found".into()) }
+        for addr in found {
+            println!("{addr:#04x}");
+        }
+        return Ok(());
+    }
+
+    let addr = match args.addr {
+        Some(addr) => addr,
+        None => ADDRESSES.into_iter().find(|&addr| probe(&mut i2c, addr)).ok_or("no device found")?,
+    };
+    // attach rather than new, so that the existing configuration is left untouched
+    let mut sensor = MAX30205::attach(addr, i2c);
+    sensor.data_format().map_err(|e| e.to_string())?;
+
+    match args.command.as_str() {
+        "read" => {
+            let temp = sensor.get_temperature().map_err(|e| e.to_string())?;
+            println!("{:.3} °C", temp.as_celsius());
+        }
+        "monitor" => {
+            let mut delay = Delay;
+            loop {
+                match sensor.get_temperature() {
+                    Ok(temp) => println!("{:.3} °C", temp.as_celsius()),
+                    Err(e) => eprintln!("error: {e}"),
+                }
+                delay.delay_ms(args.interval_ms);
+            }
+        }
+        "config" => {
+            if let Some(tos) = args.tos { sensor.set_tos(tos).map_err(|e| e.to_string())? }
+            if let Some(thyst) = args.thyst { sensor.set_thyst(thyst).map_err(|e| e.to_string())? }
+            let config = sensor.get_config().map_err(|e| e.to_string())?;
+            println!("address: {addr:#04x}");
+            println!("{config:#?}");
+            println!("tos: {:.3} °C", sensor.get_tos().map_err(|e| e.to_string())?.as_celsius());
+            println!("thyst: {:.3} °C", sensor.get_thyst().map_err(|e| e.to_string())?.as_celsius());
+        }
+        command => return Err(format!("unknown command: {command}")),
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            if !e.is_empty() { eprintln!("error: {e}\n") }
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
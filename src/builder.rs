@@ -0,0 +1,78 @@
+use embedded_hal::i2c::I2c;
+
+use crate::{MAX30205, Config, DataFormat, Thresholds, Error};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) enum Target {
+    Address(u8),
+    Scan(&'static [u8]),
+}
+
+/// A builder for constructing a driver with any combination of options, as returned by [`MAX30205::builder`].
+///
+/// ```ignore
+/// let sensor = MAX30205::builder()
+///     .scan(&ADDRESSES)
+///     .config(Config { os_mode: OsMode::Interrupt, ..Config::default() })
+///     .thresholds(Thresholds { thyst: 37.5, tos: 38.0 })
+///     .build(i2c)?;
+/// ```
+///
+/// By default, the first device found among [`ADDRESSES`](crate::ADDRESSES) is initialized as by [`MAX30205::new`].
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MAX30205Builder {
+    pub(crate) target: Target,
+    pub(crate) config: Option<Config>,
+    pub(crate) thresholds: Option<Thresholds>,
+    pub(crate) data_format: Option<DataFormat>,
+    pub(crate) preserve: bool,
+}
+impl MAX30205<()> {
+    /// Starts building a driver. See [`MAX30205Builder`] for details.
+    pub fn builder() -> MAX30205Builder {
+        MAX30205Builder { target: Target::Scan(&crate::ADDRESSES), config: None, thresholds: None, data_format: None, preserve: false }
+    }
+}
+impl MAX30205Builder {
+    /// Targets the device at the given address.
+    pub fn address(self, addr: u8) -> Self {
+        Self { target: Target::Address(addr), ..self }
+    }
+    /// Targets the first device found among the given addresses (in order). See [`MAX30205::scan`] for details.
+    pub fn scan(self, addresses: &'static [u8]) -> Self {
+        Self { target: Target::Scan(addresses), ..self }
+    }
+    /// Sets the initial configuration. See [`Config`] for details.
+    pub fn config(self, config: Config) -> Self {
+        Self { config: Some(config), ..self }
+    }
+    /// Sets the initial thresholds. See [`Thresholds`] for details.
+    pub fn thresholds(self, thresholds: Thresholds) -> Self {
+        Self { thresholds: Some(thresholds), ..self }
+    }
+    /// Sets the data format, overriding that of the initial configuration (if any). See [`DataFormat`] for details.
+    pub fn data_format(self, format: DataFormat) -> Self {
+        Self { data_format: Some(format), ..self }
+    }
+    /// Sets whether the existing device settings are preserved (default `false`), e.g., when reattaching after an MCU reset.
+    /// If so, only the settings given to the builder are written, and the driver is synchronized with the data format of the device.
+    /// Otherwise, any settings not given to the builder are reset as by [`MAX30205::new`].
+    pub fn preserve_settings(self, preserve: bool) -> Self {
+        Self { preserve, ..self }
+    }
+
+    /// Constructs the driver on the given bus.
+    ///
+    /// Returns [`Error::NoDevice`] if scanning found no device,
+    /// or [`Error::OutOfRange`] if the thresholds cannot be represented in the data format.
+    pub fn build<I2C: I2c>(self, i2c: I2C) -> Result<MAX30205<I2C>, Error<I2C::Error>> {
+        MAX30205::from_builder(self, i2c)
+    }
+    /// Constructs an async driver on the given bus. See [`Self::build`] for details.
+    #[cfg(feature = "async")]
+    pub async fn build_async<I2C: embedded_hal_async::i2c::I2c>(self, i2c: I2C) -> Result<crate::MAX30205Async<I2C>, Error<I2C::Error>> {
+        crate::MAX30205Async::from_builder(self, i2c).await
+    }
+}
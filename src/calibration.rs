@@ -0,0 +1,63 @@
+/// A linear calibration model mapping measured temperatures to reference temperatures,
+/// as applied by [`MAX30205::set_calibration`](crate::MAX30205::set_calibration).
+///
+/// A measured temperature `t` is corrected to `t * gain + offset` (in Celsius).
+/// The [`Default`] value applies no correction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Calibration {
+    /// The slope of the correction.
+    pub gain: f64,
+    /// The correction (in Celsius) at a measured temperature of 0 °C.
+    pub offset: f64,
+}
+impl Default for Calibration {
+    fn default() -> Self {
+        Self { gain: 1.0, offset: 0.0 }
+    }
+}
+impl Calibration {
+    /// Constructs an offset-only (single-point) calibration.
+    pub fn from_offset(offset: f64) -> Self {
+        Self { gain: 1.0, offset }
+    }
+    /// Computes a two-point calibration from two `(measured, reference)` pairs (in Celsius),
+    /// e.g., from readings taken in reference baths at each end of the range of interest.
+    /// Returns `None` if the measured temperatures are equal (or not finite), so no slope can be determined.
+    pub fn from_points(a: (f64, f64), b: (f64, f64)) -> Option<Self> {
+        let gain = (b.1 - a.1) / (b.0 - a.0);
+        let offset = a.1 - a.0 * gain;
+        if gain.is_finite() && offset.is_finite() { Some(Self { gain, offset }) } else { None }
+    }
+    /// Applies the correction to a measured temperature (in Celsius).
+    pub fn apply(&self, measured: f64) -> f64 {
+        measured * self.gain + self.offset
+    }
+}
+
+/// A model of the self-heating error caused by the power dissipated during conversions and bus activity,
+/// as applied by [`MAX30205::set_self_heating`](crate::MAX30205::set_self_heating).
+///
+/// The error is modeled as proportional to the conversion duty cycle: continuous conversion gives the full error,
+/// whereas one-shot conversions every `interval_ms` give a fraction of 50 ms (the conversion time) per interval.
+/// The full error depends on the thermal coupling of the board, so it should be determined experimentally,
+/// e.g., by comparing continuous readings against sparse one-shot readings in a stable environment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SelfHeating {
+    /// The self-heating error (in thousandths of a degree Celsius) during continuous conversion.
+    pub continuous_mk: u16,
+    /// The interval (in milliseconds) between one-shot conversions, or `None` for continuous conversion.
+    pub interval_ms: Option<u32>,
+}
+impl SelfHeating {
+    /// Gets the modeled self-heating error (in thousandths of a degree Celsius) at the configured duty cycle.
+    pub fn error_mk(&self) -> u16 {
+        match self.interval_ms {
+            Some(interval) if interval > crate::CONVERSION_TIME_MS => {
+                (self.continuous_mk as u64 * crate::CONVERSION_TIME_MS as u64 / interval as u64) as u16
+            }
+            _ => self.continuous_mk,
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Helpers for body temperature (clinical thermometry) applications.
+//!
+//! The classification helpers operate on readings in thousandths of a degree Celsius, like [`filters`](crate::filters),
+//! whereas [`SteadyStatePredictor`] operates on [`Temperature`] readings.
+//! They are not a substitute for a validated medical device design.
+
+use crate::Temperature;
+
+/// The range (in thousandths of a degree Celsius) over which the MAX30205 is specified to have its
+/// best accuracy of ±0.1 °C, which covers the clinically important range around normal body temperature.
+pub const CLINICAL_ACCURACY_RANGE: (i32, i32) = (37_000, 39_000);
+
+/// Checks whether a reading lies within [`CLINICAL_ACCURACY_RANGE`], where the device meets its ±0.1 °C accuracy specification.
+/// Readings outside this range are still valid, but with the (lower) general accuracy of the device.
+pub fn is_clinical_grade(millicelsius: i32) -> bool {
+    (CLINICAL_ACCURACY_RANGE.0..=CLINICAL_ACCURACY_RANGE.1).contains(&millicelsius)
+}
+
+/// A body temperature classification, as returned by [`Bands::classify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Band {
+    /// Below the hypothermia threshold.
+    Hypothermia,
+    /// Between the hypothermia and fever thresholds.
+    Normal,
+    /// At or above the fever threshold.
+    Fever,
+}
+
+/// The thresholds (in thousandths of a degree Celsius) used to classify body temperatures.
+///
+/// The [`Default`] value uses the common thresholds of 35.0 °C for hypothermia and 38.0 °C for fever,
+/// but these depend on the measurement site and clinical context, so they should be chosen accordingly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Bands {
+    /// Readings below this are classified as [`Band::Hypothermia`].
+    pub hypothermia_below: i32,
+    /// Readings at or above this are classified as [`Band::Fever`].
+    pub fever_from: i32,
+}
+impl Default for Bands {
+    fn default() -> Self {
+        Self { hypothermia_below: 35_000, fever_from: 38_000 }
+    }
+}
+impl Bands {
+    /// Classifies a reading.
+    pub fn classify(&self, millicelsius: i32) -> Band {
+        if millicelsius < self.hypothermia_below {
+            Band::Hypothermia
+        } else if millicelsius >= self.fever_from {
+            Band::Fever
+        } else {
+            Band::Normal
+        }
+    }
+}
+
+/// The output of a [`SteadyStatePredictor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Prediction {
+    /// The estimated steady-state (final) temperature.
+    pub estimate: Temperature,
+    /// Whether the readings follow a settling curve and the estimate has stabilized to within the configured tolerance.
+    pub confident: bool,
+}
+
+/// Estimates the final temperature of a probe that is still warming up (e.g., an oral or axillary measurement),
+/// so a result can be reported long before the readings settle.
+///
+/// The warm-up is modeled as a first-order exponential approach to the final temperature.
+/// The last `N` readings (which must be taken at a fixed interval) are split into three equal segments,
+/// and the final temperature is extrapolated from the geometric decay of the differences between the segment means.
+/// Longer windows reduce the effect of noise, at the cost of requiring more readings before the first estimate.
+/// The estimate is reported as confident once consecutive estimates agree to within the tolerance.
+#[derive(Debug, Clone)]
+pub struct SteadyStatePredictor<const N: usize> {
+    window: [i32; N],
+    next: usize,
+    len: usize,
+    tolerance: i32,
+    last: Option<i32>,
+    stable: u8,
+}
+impl<const N: usize> SteadyStatePredictor<N> {
+    const VALID: () = assert!(N >= 3, "prediction window must hold at least three readings");
+
+    /// Constructs an empty predictor which is confident once consecutive estimates differ by at most `tolerance`.
+    pub fn new(tolerance: Temperature) -> Self {
+        let () = Self::VALID;
+        Self { window: [0; N], next: 0, len: 0, tolerance: tolerance.as_raw(), last: None, stable: 0 }
+    }
+    /// Discards all readings, e.g., before a new measurement.
+    pub fn reset(&mut self) {
+        *self = Self::new(Temperature::from_raw(self.tolerance));
+    }
+    /// Feeds a new reading, returning the current prediction once the window is full.
+    pub fn update(&mut self, temp: Temperature) -> Option<Prediction> {
+        self.window[self.next] = temp.as_raw();
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+        if self.len < N { return None }
+
+        // sums over three equal segments of the newest readings, oldest first
+        let m = N / 3;
+        let segment = |i: usize| (0..m).map(|j| self.window[(self.next + N - 3 * m + i * m + j) % N] as i64).sum::<i64>();
+        let (a, b, c) = (segment(0), segment(1), segment(2));
+        let (d1, d2) = (b - a, c - b);
+
+        let (sum, settling) = if d2 == 0 {
+            (c, true) // already settled
+        } else if d1.signum() == d2.signum() && d2.abs() < d1.abs() {
+            (c + d2 * d2 / (d1 - d2), true) // remaining geometric series d2 r / (1 - r) with r = d2 / d1
+        } else {
+            (c, false) // not a settling curve (e.g., noise dominated or the probe was moved)
+        };
+        let estimate = crate::codec::div_round(sum as i128, m as i128) as i32;
+
+        match self.last {
+            Some(last) if settling && (estimate - last).abs() <= self.tolerance => self.stable = self.stable.saturating_add(1),
+            _ => self.stable = 0,
+        }
+        self.last = Some(estimate);
+        Some(Prediction { estimate: Temperature::from_raw(estimate), confident: self.stable >= 2 })
+    }
+}
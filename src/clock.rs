@@ -0,0 +1,24 @@
+/// A source of timestamps (in ticks of any fixed rate), used to timestamp readings without depending on a particular timer implementation.
+///
+/// This is implemented for closures such as `|| timer.millis()`, and (with the `embassy` feature) by `EmbassyClock`.
+/// Timestamps may wrap around, so consumers compare them with wrapping arithmetic.
+pub trait Clock {
+    /// Gets the current time.
+    fn now(&self) -> u32;
+}
+impl<F: Fn() -> u32> Clock for F {
+    fn now(&self) -> u32 {
+        self()
+    }
+}
+
+/// A [`Clock`] reading the embassy time driver, in milliseconds.
+#[cfg(feature = "embassy")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmbassyClock;
+#[cfg(feature = "embassy")]
+impl Clock for EmbassyClock {
+    fn now(&self) -> u32 {
+        embassy_time::Instant::now().as_millis() as u32
+    }
+}
@@ -0,0 +1,56 @@
+//! Device-free encoding and decoding of temperature and threshold register values.
+//!
+//! These are the exact conversions used by the drivers, exposed so that raw register values harvested elsewhere
+//! (e.g., from logs or radio frames, see [`Measurement`](crate::Measurement)) can be decoded identically on a host.
+//! Register values are the big-endian 16-bit contents of the temperature or threshold registers
+//! (see [`i16::from_be_bytes`]), and all functions are `const`.
+
+use crate::{DataFormat, Temperature};
+
+/// The temperature (in Celsius) represented by one LSB of the temperature and threshold registers.
+pub const CELSIUS_PER_LSB: f64 = 0.00390625;
+
+/// Decodes a raw register value encoded with the given data format.
+pub const fn decode(raw: i16, format: DataFormat) -> Temperature {
+    Temperature::from_raw(raw as i32 + format.offset())
+}
+/// Decodes a raw register value encoded with the given data format into Celsius.
+pub const fn decode_celsius(raw: i16, format: DataFormat) -> f64 {
+    (raw as i32 + format.offset()) as f64 * CELSIUS_PER_LSB
+}
+/// Decodes a raw register value encoded with the given data format into thousandths of a degree Celsius (rounded to the nearest integer).
+pub const fn decode_millicelsius(raw: i16, format: DataFormat) -> i32 {
+    lsb_to_millicelsius((raw as i32 + format.offset()) as i64) as i32
+}
+/// Encodes a temperature as a raw register value in the given data format, or `None` if it is not representable.
+pub const fn encode(temperature: Temperature, format: DataFormat) -> Option<i16> {
+    let raw = temperature.as_raw() - format.offset();
+    if raw >= i16::MIN as i32 && raw <= i16::MAX as i32 { Some(raw as i16) } else { None }
+}
+/// Encodes a temperature (in Celsius, rounded to the nearest step of 1/256 °C) as a raw register value in the given data format,
+/// or `None` if it is not representable (including NaN).
+pub const fn encode_celsius(celsius: f64, format: DataFormat) -> Option<i16> {
+    let rounded = round_for_cast(celsius / CELSIUS_PER_LSB - format.offset() as f64);
+    if rounded > i16::MIN as f64 - 1.0 && rounded < i16::MAX as f64 + 1.0 { Some(rounded as i16) } else { None }
+}
+
+// The rounding used throughout the crate, which is to the nearest integer with ties away from zero.
+
+/// Rounds `num / den` (with `den > 0`) to the nearest integer.
+pub(crate) const fn div_round(num: i128, den: i128) -> i128 {
+    if num >= 0 { (num + den / 2) / den } else { (num - den / 2) / den }
+}
+/// Offsets `x` by one half away from zero, so that a (truncating) `as` cast of the result rounds `x` to the nearest integer.
+/// This stands in for `f64::round`, which is not available in core. NaN stays NaN, so the cast gives zero.
+pub(crate) const fn round_for_cast(x: f64) -> f64 {
+    if x >= 0.0 { x + 0.5 } else { x - 0.5 }
+}
+/// Converts a count of LSBs to thousandths of a degree Celsius, rounded to the nearest integer.
+/// One LSB is exactly 1000/256 = 125/32 m°C, so this is exact integer math.
+pub(crate) const fn lsb_to_millicelsius(lsb: i64) -> i64 {
+    div_round(lsb as i128 * 125, 32) as i64
+}
+/// Converts thousandths of a degree Celsius to a count of LSBs, rounded to the nearest integer. See [`lsb_to_millicelsius`].
+pub(crate) const fn millicelsius_to_lsb(millicelsius: i64) -> i64 {
+    div_round(millicelsius as i128 * 32, 125) as i64
+}
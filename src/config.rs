@@ -0,0 +1,208 @@
+const SHUTDOWN:    u8 = 1 << 0;
+const OS_MODE:     u8 = 1 << 1;
+const POLARITY:    u8 = 1 << 2;
+const FAULT_QUEUE: u8 = 0b11 << 3;
+const FORMAT:      u8 = 1 << 5;
+const TIMEOUT:     u8 = 1 << 6;
+const ONE_SHOT:    u8 = 1 << 7;
+
+/// The operating mode of the OS (overtemperature shutdown) output pin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OsMode {
+    /// The OS output is asserted while the temperature exceeds TOS,
+    /// and is deasserted once it falls below THYST, acting as a thermostat output.
+    /// This is the power-on default.
+    Comparator,
+    /// The OS output is asserted when the temperature exceeds TOS or falls below THYST,
+    /// and remains asserted until any register is read, acting as an interrupt source.
+    Interrupt,
+}
+
+/// The active level of the OS output pin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Polarity {
+    /// The OS output is driven low when asserted. This is the power-on default.
+    ActiveLow,
+    /// The OS output is driven high when asserted.
+    ActiveHigh,
+}
+
+/// The number of consecutive faults (temperature readings outside the threshold window)
+/// required before the OS output is asserted. Higher values filter out noise at the cost of response time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FaultQueue {
+    /// A single fault asserts the OS output. This is the power-on default.
+    One,
+    /// Two consecutive faults are required.
+    Two,
+    /// Four consecutive faults are required.
+    Four,
+    /// Six consecutive faults are required.
+    Six,
+}
+
+/// The encoding used by the temperature and threshold registers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataFormat {
+    /// Two's complement with 0x0000 representing 0 °C, covering -128 °C to +127.996 °C.
+    /// This is the power-on default.
+    Normal,
+    /// Two's complement with 0x0000 representing 64 °C, covering -64 °C to +191.996 °C.
+    Extended,
+}
+impl DataFormat {
+    /// The temperature (in register LSBs of 1/256 °C) represented by a raw register value of zero.
+    pub(crate) const fn offset(self) -> i32 {
+        match self {
+            DataFormat::Normal => 0,
+            DataFormat::Extended => 64 * 256,
+        }
+    }
+}
+
+/// The decoded contents of the configuration register.
+///
+/// The [`Default`] value matches the power-on state of the device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    /// Whether the device is in power saving (shutdown) mode. See [`MAX30205::power_down`](crate::MAX30205::power_down).
+    pub shutdown: bool,
+    /// Whether a one-shot conversion is requested (or still in progress). See [`MAX30205::update_once`](crate::MAX30205::update_once).
+    pub one_shot: bool,
+    /// The operating mode of the OS output pin.
+    pub os_mode: OsMode,
+    /// The active level of the OS output pin.
+    pub os_polarity: Polarity,
+    /// The number of consecutive faults required to assert the OS output.
+    pub fault_queue: FaultQueue,
+    /// The encoding used by the temperature and threshold registers.
+    pub data_format: DataFormat,
+    /// Whether the I2C bus timeout is enabled. See [`MAX30205::set_bus_timeout`](crate::MAX30205::set_bus_timeout).
+    pub bus_timeout: bool,
+}
+impl Default for Config {
+    fn default() -> Self {
+        Self::from_bits(0)
+    }
+}
+impl Config {
+    /// Decodes a raw configuration register value.
+    pub fn from_bits(bits: u8) -> Self {
+        Self {
+            shutdown: bits & SHUTDOWN != 0,
+            one_shot: bits & ONE_SHOT != 0,
+            os_mode: if bits & OS_MODE != 0 { OsMode::Interrupt } else { OsMode::Comparator },
+            os_polarity: if bits & POLARITY != 0 { Polarity::ActiveHigh } else { Polarity::ActiveLow },
+            fault_queue: match (bits & FAULT_QUEUE) >> 3 {
+                0 => FaultQueue::One,
+                1 => FaultQueue::Two,
+                2 => FaultQueue::Four,
+                _ => FaultQueue::Six,
+            },
+            data_format: if bits & FORMAT != 0 { DataFormat::Extended } else { DataFormat::Normal },
+            bus_timeout: bits & TIMEOUT == 0, // the register bit is a disable flag
+        }
+    }
+    /// Encodes this configuration as a raw configuration register value.
+    pub fn bits(&self) -> u8 {
+        let mut bits = 0;
+        if self.shutdown { bits |= SHUTDOWN }
+        if self.one_shot { bits |= ONE_SHOT }
+        if self.os_mode == OsMode::Interrupt { bits |= OS_MODE }
+        if self.os_polarity == Polarity::ActiveHigh { bits |= POLARITY }
+        bits |= match self.fault_queue {
+            FaultQueue::One => 0,
+            FaultQueue::Two => 1,
+            FaultQueue::Four => 2,
+            FaultQueue::Six => 3,
+        } << 3;
+        if self.data_format == DataFormat::Extended { bits |= FORMAT }
+        if !self.bus_timeout { bits |= TIMEOUT }
+        bits
+    }
+}
+
+/// The temperature thresholds (in Celsius) that control the OS output.
+///
+/// The [`Default`] value matches the power-on state of the device.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Thresholds {
+    /// The hysteresis threshold. See [`MAX30205::set_thyst`](crate::MAX30205::set_thyst).
+    pub thyst: f64,
+    /// The overtemperature shutdown threshold. See [`MAX30205::set_tos`](crate::MAX30205::set_tos).
+    pub tos: f64,
+}
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self { thyst: 75.0, tos: 80.0 }
+    }
+}
+
+/// A snapshot of the complete device configuration, as returned by [`MAX30205::save_state`](crate::MAX30205::save_state).
+///
+/// The thresholds are stored as raw register values, so restoring them is exact regardless of data format.
+/// [`DeviceState::to_bytes`] and [`DeviceState::from_bytes`] can be used to persist a snapshot, e.g., across MCU deep sleep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceState {
+    /// The configuration register.
+    pub config: Config,
+    /// The raw hysteresis threshold register.
+    pub thyst: i16,
+    /// The raw overtemperature shutdown threshold register.
+    pub tos: i16,
+}
+impl DeviceState {
+    /// Encodes this snapshot as bytes.
+    pub fn to_bytes(&self) -> [u8; 5] {
+        let [thyst_hi, thyst_lo] = self.thyst.to_be_bytes();
+        let [tos_hi, tos_lo] = self.tos.to_be_bytes();
+        [self.config.bits(), thyst_hi, thyst_lo, tos_hi, tos_lo]
+    }
+    /// Decodes a snapshot previously encoded with [`DeviceState::to_bytes`].
+    pub fn from_bytes(bytes: [u8; 5]) -> Self {
+        Self {
+            config: Config::from_bits(bytes[0]),
+            thyst: i16::from_be_bytes([bytes[1], bytes[2]]),
+            tos: i16::from_be_bytes([bytes[3], bytes[4]]),
+        }
+    }
+}
+
+/// The result of comparing the device registers against the values last written by the driver,
+/// as returned by [`MAX30205::health_check`](crate::MAX30205::health_check).
+///
+/// Each comparison is `None` if the driver has not written that register (e.g., after [`MAX30205::attach`](crate::MAX30205::attach)),
+/// so there is no expected value to compare against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HealthReport {
+    /// The device configuration as read back during the check.
+    pub state: DeviceState,
+    /// Whether the configuration register matches (ignoring any pending one-shot request).
+    pub config_ok: Option<bool>,
+    /// Whether the hysteresis threshold register matches.
+    pub thyst_ok: Option<bool>,
+    /// Whether the overtemperature shutdown threshold register matches.
+    pub tos_ok: Option<bool>,
+}
+impl HealthReport {
+    /// Checks that no register was found to differ from its expected value.
+    pub fn is_healthy(&self) -> bool {
+        self.config_ok != Some(false) && self.thyst_ok != Some(false) && self.tos_ok != Some(false)
+    }
+}
@@ -0,0 +1,58 @@
+use core::ops::{Deref, DerefMut};
+
+use embedded_hal::i2c::I2c;
+
+use crate::{MAX30205, Address, ConstAddress, Config, Thresholds, Error};
+
+/// A MAX30205 sensor wrapper whose address is fixed at compile time.
+///
+/// Constructing this type fails to compile if `ADDR` is not one of the [`ADDRESSES`](crate::ADDRESSES)
+/// a MAX30205 can be strapped to, and the address does not need to be passed around at runtime
+/// (see [`MAX30205Const::address`]).
+/// The wrapped driver stores the address as a [`ConstAddress`], which takes no space,
+/// and the full [`MAX30205`] API is available through [`Deref`].
+pub struct MAX30205Const<I2C, const ADDR: u8> {
+    inner: MAX30205<I2C, ConstAddress<ADDR>>,
+}
+impl<I2C, const ADDR: u8> MAX30205Const<I2C, ADDR> {
+    const VALID: () = assert!(Address::new(ADDR).is_some(), "not a valid MAX30205 address");
+
+    /// Constructs a MAX30205 sensor wrapper for an already-configured device. See [`MAX30205::attach`] for details.
+    pub fn attach(i2c: I2C) -> Self {
+        let () = Self::VALID;
+        Self { inner: MAX30205::attach_at(ConstAddress, i2c) }
+    }
+    /// Gets the I2C address of the device, which is known at compile time.
+    pub const fn address() -> u8 {
+        let () = Self::VALID;
+        ADDR
+    }
+    /// Converts this into a runtime-addressed driver with the same state.
+    pub fn into_inner(self) -> MAX30205<I2C> {
+        self.inner.readdress(ADDR)
+    }
+}
+impl<I2C: I2c, const ADDR: u8> MAX30205Const<I2C, ADDR> {
+    /// Constructs and initializes a MAX30205 sensor wrapper. See [`MAX30205::new`] for details.
+    pub fn new(i2c: I2C) -> Result<Self, Error<I2C::Error>> {
+        let () = Self::VALID;
+        Ok(Self { inner: MAX30205::new_with_config_at(ConstAddress, i2c, Config::default(), Thresholds { thyst: 0.0, tos: 0.0 })? }) // as in MAX30205::new
+    }
+    /// Constructs and initializes a MAX30205 sensor wrapper with the given configuration and thresholds.
+    /// See [`MAX30205::new_with_config`] for details.
+    pub fn new_with_config(i2c: I2C, config: Config, thresholds: Thresholds) -> Result<Self, Error<I2C::Error>> {
+        let () = Self::VALID;
+        Ok(Self { inner: MAX30205::new_with_config_at(ConstAddress, i2c, config, thresholds)? })
+    }
+}
+impl<I2C, const ADDR: u8> Deref for MAX30205Const<I2C, ADDR> {
+    type Target = MAX30205<I2C, ConstAddress<ADDR>>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+impl<I2C, const ADDR: u8> DerefMut for MAX30205Const<I2C, ADDR> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
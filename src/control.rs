@@ -0,0 +1,123 @@
+//! Software control and alarm logic driven by temperature readings,
+//! for systems where the MCU (rather than the OS output of the device) acts on the temperature.
+
+use embedded_hal::digital::{Error as _, ErrorKind, OutputPin};
+
+use crate::Temperature;
+
+/// A bang-bang (on/off) heater controller with hysteresis.
+///
+/// The heater is turned on when the temperature falls below `setpoint - hysteresis`,
+/// and turned off once it reaches `setpoint`, so it does not chatter around a single threshold.
+/// The heater starts off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SoftThermostat {
+    setpoint: Temperature,
+    hysteresis: Temperature,
+    heating: bool,
+}
+impl SoftThermostat {
+    /// Constructs a controller with the given setpoint and (nonnegative) hysteresis band.
+    pub fn new(setpoint: Temperature, hysteresis: Temperature) -> Self {
+        Self { setpoint, hysteresis, heating: false }
+    }
+    /// Changes the setpoint, keeping the current heater state.
+    pub fn set_setpoint(&mut self, setpoint: Temperature) {
+        self.setpoint = setpoint;
+    }
+    /// Gets the setpoint.
+    pub fn setpoint(&self) -> Temperature {
+        self.setpoint
+    }
+    /// Checks whether the heater is currently on.
+    pub fn is_heating(&self) -> bool {
+        self.heating
+    }
+    /// Updates the controller with a new reading, returning whether the heater should be on.
+    pub fn update(&mut self, temp: Temperature) -> bool {
+        if temp >= self.setpoint {
+            self.heating = false;
+        } else if temp < self.setpoint - self.hysteresis {
+            self.heating = true;
+        }
+        self.heating
+    }
+    /// Updates the controller with a new reading and drives the heater pin accordingly (high is on),
+    /// returning whether the heater is on.
+    pub fn drive(&mut self, temp: Temperature, heater: &mut impl OutputPin) -> Result<bool, ErrorKind> {
+        let on = self.update(temp);
+        if on { heater.set_high() } else { heater.set_low() }.map_err(|e| e.kind())?;
+        Ok(on)
+    }
+}
+
+/// A threshold crossing, as reported by [`AlarmMonitor::update`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlarmEvent {
+    /// The temperature rose above the high threshold.
+    EnteredHigh,
+    /// The temperature fell below the low threshold.
+    EnteredLow,
+    /// The temperature returned inside the thresholds (by at least the hysteresis) after entering an alarm.
+    ReturnedToNormal,
+}
+
+/// The current alarm zone of an [`AlarmMonitor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlarmZone {
+    /// Between the thresholds.
+    Normal,
+    /// Above the high threshold.
+    High,
+    /// Below the low threshold.
+    Low,
+}
+
+/// A software equivalent of the device alarm for applications without the OS output wired,
+/// which reports high and low threshold crossings as events.
+///
+/// An alarm is entered as soon as a reading crosses its threshold, but is only left once a reading is back inside
+/// the threshold by at least the hysteresis, so noise around a threshold does not produce a stream of events.
+/// The monitor starts in [`AlarmZone::Normal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AlarmMonitor {
+    low: Temperature,
+    high: Temperature,
+    hysteresis: Temperature,
+    zone: AlarmZone,
+}
+impl AlarmMonitor {
+    /// Constructs a monitor with the given low and high thresholds and (nonnegative) hysteresis.
+    pub fn new(low: Temperature, high: Temperature, hysteresis: Temperature) -> Self {
+        Self { low, high, hysteresis, zone: AlarmZone::Normal }
+    }
+    /// Gets the current alarm zone.
+    pub fn zone(&self) -> AlarmZone {
+        self.zone
+    }
+    /// Updates the monitor with a new reading, returning the resulting event (if any).
+    pub fn update(&mut self, temp: Temperature) -> Option<AlarmEvent> {
+        let zone = if temp > self.high {
+            AlarmZone::High
+        } else if temp < self.low {
+            AlarmZone::Low
+        } else {
+            match self.zone {
+                AlarmZone::High if temp > self.high - self.hysteresis => AlarmZone::High,
+                AlarmZone::Low if temp < self.low + self.hysteresis => AlarmZone::Low,
+                _ => AlarmZone::Normal,
+            }
+        };
+        if zone == self.zone { return None }
+        self.zone = zone;
+        Some(match zone {
+            AlarmZone::High => AlarmEvent::EnteredHigh,
+            AlarmZone::Low => AlarmEvent::EnteredLow,
+            AlarmZone::Normal => AlarmEvent::ReturnedToNormal,
+        })
+    }
+}
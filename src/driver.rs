@@ -0,0 +1,793 @@
+// Logs bus activity at the given level with the `defmt-trace` and `log` features. The arguments are not evaluated otherwise.
+macro_rules! trace_bus {
+    ($level:ident, $($arg:tt)*) => {
+        #[cfg(feature = "defmt-trace")]
+        defmt::$level!($($arg)*);
+        #[cfg(feature = "log")]
+        log::$level!($($arg)*);
+    };
+}
+
+// The driver is written once here and instantiated for both the blocking and async embedded-hal traits.
+// `$async` is spliced in front of every bus-facing `fn`, and `$await` after every bus call,
+// so the generated code is plain (non-macro) Rust for each flavor.
+macro_rules! driver {
+    ($(#[$meta:meta])* $name:ident, $core:ident, $i2c:path, $delay:path, [$($async:tt)*], [$($await:tt)*]) => {
+        $(#[$meta])*
+        pub struct $name<I2C, A = u8> {
+            core: $crate::lm75::$core<I2C, $crate::lm75::MAX30205, A>, // the bus and address, shared with other LM75-family parts
+            format: $crate::DataFormat,
+            // the last values written by the driver (with one-shot cleared), used to skip config reads and re-initialize the device
+            config: Option<$crate::Config>,
+            thyst: Option<i16>,
+            tos: Option<i16>,
+            recover_after: u8,
+            failures: u8,
+            plausible: Option<(i32, i32)>, // inclusive bounds in LSBs relative to 0 °C
+            reject_stuck: bool,
+            stale_limit: u8,
+            last_raw: i16,
+            repeats: u8, // the number of consecutive continuous-mode readings equal to last_raw
+            diagnostics: $crate::Diagnostics,
+            // calibration, mapping a measured temperature t (in LSBs relative to 0 °C) to t * gain / 2^16 + offset
+            gain: i32,
+            offset: i16,
+            heating: i16, // self-heating compensation in LSBs, subtracted after calibration
+        }
+        impl<I2C> $name<I2C> {
+            /// Constructs a MAX30205 sensor wrapper for an already-configured device at the given address.
+            /// Unlike [`Self::new`], this performs no bus communication and leaves the device state untouched.
+            ///
+            /// The driver assumes the [`DataFormat::Normal`](crate::DataFormat::Normal) data format.
+            /// If the device may be using the extended format, call [`Self::data_format`] once to synchronize.
+            pub fn attach(addr: u8, i2c: I2C) -> Self {
+                Self::attach_at(addr, i2c)
+            }
+        }
+        impl<I2C, A: $crate::AddressSource> $name<I2C, A> {
+            // attach with any address storage, e.g., for MAX30205Const
+            pub(crate) fn attach_at(addr: A, i2c: I2C) -> Self {
+                Self { core: $crate::lm75::$core::with_address(addr, i2c), format: $crate::DataFormat::Normal, config: None, thyst: None, tos: None, recover_after: 0, failures: 0, plausible: None, reject_stuck: false, stale_limit: 0, last_raw: 0, repeats: 0, diagnostics: Default::default(), gain: 1 << 16, offset: 0, heating: 0 }
+            }
+
+            /// Gets the I2C address of the device.
+            pub fn address(&self) -> u8 {
+                self.core.address()
+            }
+
+            /// Gets the bus back, e.g., to hand it to another driver, or to scan for the device again later.
+            /// The device is left in its current state; see [`Self::power_down_and_release`] to stop conversions first,
+            /// or [`MAX30205AutoShutdown`](crate::MAX30205AutoShutdown) to do so automatically when the driver is dropped.
+            pub fn release(self) -> I2C {
+                self.core.into_inner()
+            }
+
+            /// Gets the counters of the bus operations performed by the driver, e.g., for field debugging.
+            /// See [`Diagnostics`](crate::Diagnostics) for details.
+            ///
+            /// Retries performed by the bus itself (e.g., [`RetryI2c`](crate::RetryI2c)) are invisible to the driver,
+            /// and are instead counted by [`RetryI2c::retries`](crate::RetryI2c::retries).
+            pub fn diagnostics(&self) -> $crate::Diagnostics {
+                self.diagnostics
+            }
+            /// Resets all the counters returned by [`Self::diagnostics`] to zero.
+            pub fn reset_diagnostics(&mut self) {
+                self.diagnostics = Default::default();
+            }
+
+            /// Copies the driver state onto a different bus handle.
+            /// Every field other than the bus must be `Copy` so that wrappers such as
+            /// [`MAX30205Array`](crate::MAX30205Array) can store detached (bus-less) drivers.
+            #[allow(dead_code)]
+            pub(crate) fn rebind<J>(&self, i2c: J) -> $name<J, A> {
+                self.with_core($crate::lm75::$core::with_address(self.core.address_source(), i2c))
+            }
+            /// Moves the driver state to a different address storage, e.g., to turn a [`MAX30205Const`](crate::MAX30205Const)
+            /// back into a runtime-addressed driver.
+            #[allow(dead_code)]
+            pub(crate) fn readdress<B: $crate::AddressSource>(self, addr: B) -> $name<I2C, B> {
+                let state = self.rebind(());
+                state.with_core($crate::lm75::$core::with_address(addr, self.release()))
+            }
+            fn with_core<J, B>(&self, core: $crate::lm75::$core<J, $crate::lm75::MAX30205, B>) -> $name<J, B> {
+                $name {
+                    core, format: self.format,
+                    config: self.config, thyst: self.thyst, tos: self.tos,
+                    recover_after: self.recover_after, failures: self.failures,
+                    plausible: self.plausible, reject_stuck: self.reject_stuck,
+                    stale_limit: self.stale_limit, last_raw: self.last_raw, repeats: self.repeats,
+                    diagnostics: self.diagnostics, gain: self.gain, offset: self.offset, heating: self.heating,
+                }
+            }
+        }
+        impl<I2C: $i2c> $name<I2C> {
+            /// Checks whether a device acknowledges the given address.
+            /// A NACK means no device is present, but any other bus error is propagated.
+            $($async)* fn acks(i2c: &mut I2C, addr: u8) -> Result<bool, $crate::Error<I2C::Error>> {
+                $crate::lm75::$core::<I2C, $crate::lm75::MAX30205>::acks(i2c, addr) $($await)*
+            }
+            /// Scans for available devices on each of the given addresses (in order).
+            /// Returns the first found valid address, or [`Error::NoDevice`](crate::Error::NoDevice) if no devices are found.
+            ///
+            /// Typically, `addresses` is [`ADDRESSES`](crate::ADDRESSES), which lists every address a MAX30205 can be strapped to.
+            /// A shorter list can be given to limit the scan time, or if other devices are known to occupy some addresses.
+            ///
+            /// Addresses that are not acknowledged are skipped, but any other bus error (e.g., arbitration loss)
+            /// aborts the scan and is returned, so that a faulty bus is not mistaken for an absent device.
+            ///
+            /// Note that a found device is not necessarily a MAX30205 sensor,
+            /// as it could be that some other device has the same address as a MAX30205 device.
+            /// See [`Self::probe`] for a more thorough check.
+            pub $($async)* fn scan(i2c: &mut I2C, addresses: &[u8]) -> Result<u8, $crate::Error<I2C::Error>> {
+                for addr in addresses.iter().copied() {
+                    if Self::acks(i2c, addr) $($await)* ? { return Ok(addr) }
+                }
+                Err($crate::Error::NoDevice)
+            }
+            /// Scans for available devices on each of the given addresses,
+            /// returning all responding addresses (in order) rather than stopping at the first.
+            /// See [`Self::scan`] for details.
+            pub $($async)* fn scan_all<const N: usize>(i2c: &mut I2C, addresses: &[u8; N]) -> Result<heapless::Vec<u8, N>, $crate::Error<I2C::Error>> {
+                let mut res = heapless::Vec::new();
+                for addr in addresses.iter().copied() {
+                    if Self::acks(i2c, addr) $($await)* ? { res.push(addr).unwrap() } // capacity is exactly the number of addresses
+                }
+                Ok(res)
+            }
+            /// Performs an SMBus alert response read to find which device asserted a shared (interrupt mode) alert line.
+            /// Returns the address of the responding device (the lowest address wins if several respond),
+            /// or `None` if no device currently has its alert asserted.
+            ///
+            /// The responding device deasserts its alert, so repeated calls can be used to find every alerting device.
+            /// The returned address can be compared against [`Self::address`] to find the corresponding driver.
+            pub $($async)* fn alert_response(i2c: &mut I2C) -> Result<Option<u8>, $crate::Error<I2C::Error>> {
+                let mut res = [0u8];
+                match i2c.read($crate::ALERT_RESPONSE_ADDRESS, &mut res) $($await)* {
+                    Ok(()) => Ok(Some(res[0] >> 1)), // the address is returned in the upper 7 bits
+                    Err(e) => match embedded_hal::i2c::Error::kind(&e) {
+                        embedded_hal::i2c::ErrorKind::NoAcknowledge(_) => Ok(None),
+                        _ => Err($crate::Error::Bus(e)),
+                    },
+                }
+            }
+            /// Heuristically checks whether the device at the given address is a MAX30205 sensor,
+            /// which gives more confidence than the simple presence check performed by [`Self::scan`].
+            /// This only reads from the device and leaves its state untouched.
+            ///
+            /// Returns `false` if no device acknowledges the address, or if its registers hold values
+            /// inconsistent with a MAX30205: the configuration register must read back consistently (with the
+            /// threshold registers readable in between),
+            /// and the temperature must lie within the range the device can physically report (-40 °C to +125 °C).
+            /// Because the MAX30205 uses the common LM75-style register map, other sensors may still pass this check.
+            pub $($async)* fn probe(addr: u8, i2c: &mut I2C) -> Result<bool, $crate::Error<I2C::Error>> {
+                if !Self::acks(i2c, addr) $($await)* ? { return Ok(false) }
+
+                let mut dev = $name::attach(addr, &mut *i2c);
+                let config = dev.get_config() $($await)* ?; // also syncs the data format
+                let temp = dev.get_temperature_millicelsius() $($await)* ?;
+                dev.read_register16($crate::Register::Thyst) $($await)* ?;
+                dev.read_register16($crate::Register::Tos) $($await)* ?;
+                let mut again = dev.get_config() $($await)* ?;
+                again.one_shot = config.one_shot; // a pending one-shot may complete between reads
+
+                Ok(config == again && (-40_000..=125_000).contains(&temp))
+            }
+            /// Constructs a MAX30205 sensor wrapper targeting the given address.
+            /// If the address is unknown, [`Self::scan`] can be used.
+            /// For other combinations of options, see [`MAX30205::builder`](crate::MAX30205::builder).
+            ///
+            /// Also initializes the device for usage, which requires the I2C bus for communication.
+            /// The initial state disables power saving mode.
+            /// See [`Self::power_down`] for details.
+            pub $($async)* fn new(addr: u8, i2c: I2C) -> Result<Self, $crate::Error<I2C::Error>> {
+                Self::new_with_config(addr, i2c, $crate::Config::default(), $crate::Thresholds { thyst: 0.0, tos: 0.0 }) $($await)*
+            }
+            /// Constructs a MAX30205 sensor wrapper targeting the given address.
+            /// This is identical to [`Self::new`], except that the threshold registers are left untouched,
+            /// preserving any previously programmed alarm setup.
+            pub $($async)* fn new_preserving_thresholds(addr: u8, i2c: I2C) -> Result<Self, $crate::Error<I2C::Error>> {
+                let mut res = Self::attach(addr, i2c);
+                res.set_config($crate::Config::default()) $($await)* ?;
+                Ok(res)
+            }
+            /// Constructs a MAX30205 sensor wrapper targeting the given address,
+            /// and initializes the device with the given configuration and thresholds.
+            /// See [`Self::new`] for details.
+            ///
+            /// Returns [`Error::OutOfRange`](crate::Error::OutOfRange) without touching the device
+            /// if the thresholds cannot be represented in the configured [`DataFormat`](crate::DataFormat).
+            pub $($async)* fn new_with_config(addr: u8, i2c: I2C, config: $crate::Config, thresholds: $crate::Thresholds) -> Result<Self, $crate::Error<I2C::Error>> {
+                Self::new_with_config_at(addr, i2c, config, thresholds) $($await)*
+            }
+
+            pub(crate) $($async)* fn from_builder(builder: $crate::MAX30205Builder, mut i2c: I2C) -> Result<Self, $crate::Error<I2C::Error>> {
+                let addr = match builder.target {
+                    $crate::builder::Target::Address(addr) => addr,
+                    $crate::builder::Target::Scan(addresses) => Self::scan(&mut i2c, addresses) $($await)* ?,
+                };
+                let config = builder.config.map(|c| $crate::Config { data_format: builder.data_format.unwrap_or(c.data_format), ..c });
+                if !builder.preserve {
+                    let config = config.unwrap_or($crate::Config { data_format: builder.data_format.unwrap_or($crate::DataFormat::Normal), ..Default::default() });
+                    let thresholds = builder.thresholds.unwrap_or($crate::Thresholds { thyst: 0.0, tos: 0.0 }); // as in new
+                    return Self::new_with_config(addr, i2c, config, thresholds) $($await)*;
+                }
+
+                let mut res = Self::attach(addr, i2c);
+                match (config, builder.data_format) {
+                    (Some(config), _) => res.set_config(config) $($await)* ?,
+                    (None, Some(format)) => res.set_data_format(format) $($await)* ?,
+                    (None, None) => { res.data_format() $($await)* ?; }
+                }
+                if let Some(thresholds) = builder.thresholds {
+                    let thyst = $crate::codec::encode_celsius(thresholds.thyst, res.format).ok_or($crate::Error::OutOfRange)?;
+                    let tos = $crate::codec::encode_celsius(thresholds.tos, res.format).ok_or($crate::Error::OutOfRange)?;
+                    res.write_register16($crate::Register::Thyst, thyst) $($await)* ?;
+                    res.write_register16($crate::Register::Tos, tos) $($await)* ?;
+                }
+                Ok(res)
+            }
+
+        }
+        impl<I2C: $i2c, A: $crate::AddressSource> $name<I2C, A> {
+            // new_with_config with any address storage, e.g., for MAX30205Const
+            pub(crate) $($async)* fn new_with_config_at(addr: A, i2c: I2C, config: $crate::Config, thresholds: $crate::Thresholds) -> Result<Self, $crate::Error<I2C::Error>> {
+                // validate the thresholds before touching the device
+                let thyst = $crate::codec::encode_celsius(thresholds.thyst, config.data_format).ok_or($crate::Error::OutOfRange)?;
+                let tos = $crate::codec::encode_celsius(thresholds.tos, config.data_format).ok_or($crate::Error::OutOfRange)?;
+
+                let mut res = Self::attach_at(addr, i2c);
+                res.set_config(config) $($await)* ?;
+                res.write_register16($crate::Register::Thyst, thyst) $($await)* ?;
+                res.write_register16($crate::Register::Tos, tos) $($await)* ?;
+                Ok(res)
+            }
+
+            /// Checks whether the device still acknowledges its address, e.g., to periodically detect connector faults.
+            /// This is a single empty write, so it is cheaper than any register access.
+            ///
+            /// Returns `false` if the device does not respond, but propagates any other bus error.
+            pub $($async)* fn is_present(&mut self) -> Result<bool, $crate::Error<I2C::Error>> {
+                self.core.is_present() $($await)*
+            }
+
+            /// Frees a stuck bus using [`recover_bus`](crate::recover_bus), then checks whether the device responds again.
+            /// On success, this also resets the consecutive failure count used by [`Self::set_auto_recovery`].
+            ///
+            /// This is only usable if the HAL permits driving the bus lines as GPIO while the bus is owned by the driver.
+            /// Otherwise, call [`recover_bus`](crate::recover_bus) while the bus is torn down, and [`Self::is_present`] afterwards.
+            /// Pin failures are reported as [`Error::Pin`](crate::Error::Pin).
+            pub $($async)* fn recover_bus(&mut self, scl: &mut impl embedded_hal::digital::OutputPin, sda: &mut impl embedded_hal::digital::OutputPin, delay: &mut impl embedded_hal::delay::DelayNs) -> Result<bool, $crate::Error<I2C::Error>> {
+                $crate::recover_bus(scl, sda, delay).map_err($crate::Error::Pin)?;
+                let present = self.is_present() $($await)* ?;
+                if present { self.failures = 0 }
+                Ok(present)
+            }
+
+            // all register accesses go through bus_read and bus_write, which handle failure tracking and recovery
+            $($async)* fn bus_read(&mut self, reg: $crate::Register, buf: &mut [u8]) -> Result<(), $crate::Error<I2C::Error>> {
+                let res = self.core.read_raw(reg, buf) $($await)*;
+                trace_bus!(trace, "MAX30205 {:#x}: read {:?} = {:?} ({:?})", self.core.address(), reg, buf, res.as_ref().map_err(embedded_hal::i2c::Error::kind));
+                self.complete(reg, $crate::Access::Read, res) $($await)*
+            }
+            $($async)* fn bus_write(&mut self, reg: $crate::Register, value: &[u8]) -> Result<(), $crate::Error<I2C::Error>> {
+                let res = self.core.write_raw(reg, value) $($await)*;
+                trace_bus!(trace, "MAX30205 {:#x}: write {:?} = {:?} ({:?})", self.core.address(), reg, value, res.as_ref().map_err(embedded_hal::i2c::Error::kind));
+                self.complete(reg, $crate::Access::Write, res) $($await)*
+            }
+            $($async)* fn complete(&mut self, register: $crate::Register, access: $crate::Access, res: Result<(), I2C::Error>) -> Result<(), $crate::Error<I2C::Error>> {
+                let stats = &mut self.diagnostics;
+                stats.transactions = stats.transactions.wrapping_add(1);
+                match res {
+                    Ok(()) => {
+                        self.failures = 0;
+                        Ok(())
+                    }
+                    Err(e) => {
+                        stats.errors = stats.errors.wrapping_add(1);
+                        if let embedded_hal::i2c::ErrorKind::NoAcknowledge(_) = embedded_hal::i2c::Error::kind(&e) {
+                            stats.nacks = stats.nacks.wrapping_add(1);
+                        }
+                        self.failures = self.failures.saturating_add(1);
+                        if self.recover_after != 0 && self.failures >= self.recover_after && self.reinitialize() $($await)* {
+                            self.failures = 0;
+                            self.diagnostics.recoveries = self.diagnostics.recoveries.wrapping_add(1);
+                            trace_bus!(warn, "MAX30205 {:#x}: re-initialized after {:?} failure", self.core.address(), register);
+                            return Err($crate::Error::Recovered { register, access, error: e });
+                        }
+                        Err($crate::Error::Register { register, access, error: e })
+                    }
+                }
+            }
+            // rewrites the cached configuration directly, bypassing complete (and therefore recursion)
+            $($async)* fn reinitialize(&mut self) -> bool {
+                let config = match self.config {
+                    Some(config) => config,
+                    None => return false, // nothing is known to restore
+                };
+                if self.core.write_raw($crate::Register::Config, &[config.bits()]) $($await)* .is_err() { return false }
+                for (reg, value) in [($crate::Register::Thyst, self.thyst), ($crate::Register::Tos, self.tos)] {
+                    if let Some(value) = value {
+                        if self.core.write_raw(reg, &value.to_be_bytes()) $($await)* .is_err() { return false }
+                    }
+                }
+                true
+            }
+
+            /// Enables automatic recovery after `after_failures` consecutive failed bus operations (or disables it if zero, the default).
+            ///
+            /// When the limit is reached, the driver re-initializes the device using the configuration and thresholds
+            /// it most recently wrote (e.g., to recover from a controller reset or device brown-out).
+            /// If that succeeds, the failed operation returns [`Error::Recovered`](crate::Error::Recovered) rather than
+            /// [`Error::Register`](crate::Error::Register), signaling that the operation itself was not performed but can be retried.
+            ///
+            /// Recovery requires the driver to have written a configuration at some point,
+            /// so a driver created by [`Self::attach`] does not recover until it has configured the device.
+            pub fn set_auto_recovery(&mut self, after_failures: u8) {
+                self.recover_after = after_failures;
+            }
+
+            /// Reads and decodes the configuration register. See [`Config`](crate::Config) for details.
+            ///
+            /// This also updates the data format used by the driver to match the device.
+            pub $($async)* fn get_config(&mut self) -> Result<$crate::Config, $crate::Error<I2C::Error>> {
+                let mut reg = [0u8];
+                self.bus_read($crate::Register::Config, &mut reg) $($await)* ?;
+                let config = $crate::Config::from_bits(reg[0]);
+                self.format = config.data_format;
+                Ok(config)
+            }
+            /// Writes the entire configuration register. See [`Config`](crate::Config) for details.
+            ///
+            /// Note that the device does not convert the stored threshold registers when the data format changes.
+            /// See [`Self::set_data_format`] for details.
+            ///
+            /// This is a single bus write, so unlike the individual setters (e.g., [`Self::set_os_mode`]),
+            /// which may need to read the register first, it cannot interleave with other users of a shared bus.
+            pub $($async)* fn set_config(&mut self, config: $crate::Config) -> Result<(), $crate::Error<I2C::Error>> {
+                self.bus_write($crate::Register::Config, &[config.bits()]) $($await)* ?;
+                self.format = config.data_format;
+                self.config = Some($crate::Config { one_shot: false, ..config });
+                Ok(())
+            }
+            /// Reads the configuration register and adopts it as the driver's cached configuration,
+            /// discarding any changes made by the driver that the device may have lost (e.g., due to a brown-out).
+            ///
+            /// The individual configuration setters (e.g., [`Self::power_down`]) modify the cached configuration,
+            /// so that they only need a single bus write. The cache is first filled by [`Self::set_config`]
+            /// (including during construction), or by the first such setter on a driver created with [`Self::attach`].
+            /// This only needs to be called if something else may have modified the device configuration.
+            pub $($async)* fn refresh_config(&mut self) -> Result<$crate::Config, $crate::Error<I2C::Error>> {
+                let config = self.get_config() $($await)* ?;
+                self.config = Some($crate::Config { one_shot: false, ..config });
+                Ok(config)
+            }
+            // a read-modify-write cannot be made atomic with I2c::transaction, as every write in a transaction
+            // must be known up front, whereas the value written here depends on the value read
+            $($async)* fn modify_config(&mut self, f: impl FnOnce(&mut $crate::Config)) -> Result<(), $crate::Error<I2C::Error>> {
+                let mut config = match self.config {
+                    Some(config) => config,
+                    None => self.get_config() $($await)* ?,
+                };
+                f(&mut config);
+                self.set_config(config) $($await)*
+            }
+
+            $($async)* fn write_register16(&mut self, reg: $crate::Register, value: i16) -> Result<(), $crate::Error<I2C::Error>> {
+                let [hi, lo] = value.to_be_bytes();
+                self.bus_write(reg, &[hi, lo]) $($await)* ?;
+                match reg {
+                    $crate::Register::Thyst => self.thyst = Some(value),
+                    $crate::Register::Tos => self.tos = Some(value),
+                    _ => (),
+                }
+                Ok(())
+            }
+
+            $($async)* fn read_register16(&mut self, reg: $crate::Register) -> Result<i16, $crate::Error<I2C::Error>> {
+                let mut res = [0; 2];
+                self.bus_read(reg, &mut res) $($await)* ?;
+                Ok(i16::from_be_bytes(res))
+            }
+
+            /// Restores the device to its power-on defaults, without reconstructing the driver.
+            /// This sets the configuration to [`Config::default`](crate::Config::default),
+            /// the hysteresis threshold to 75 °C, and the overtemperature threshold to 80 °C.
+            pub $($async)* fn reset_to_defaults(&mut self) -> Result<(), $crate::Error<I2C::Error>> {
+                self.set_config($crate::Config::default()) $($await)* ?;
+                self.write_register16($crate::Register::Thyst, $crate::DEFAULT_THYST_RAW) $($await)* ?;
+                self.write_register16($crate::Register::Tos, $crate::DEFAULT_TOS_RAW) $($await)*
+            }
+
+            /// Captures the complete device configuration (config and threshold registers).
+            /// The result can later be applied with [`Self::restore_state`].
+            ///
+            /// Any pending one-shot request is not included in the snapshot.
+            pub $($async)* fn save_state(&mut self) -> Result<$crate::DeviceState, $crate::Error<I2C::Error>> {
+                let mut config = self.get_config() $($await)* ?;
+                config.one_shot = false;
+                Ok($crate::DeviceState {
+                    config,
+                    thyst: self.read_register16($crate::Register::Thyst) $($await)* ?,
+                    tos: self.read_register16($crate::Register::Tos) $($await)* ?,
+                })
+            }
+            /// Restores a device configuration previously captured by [`Self::save_state`].
+            pub $($async)* fn restore_state(&mut self, state: &$crate::DeviceState) -> Result<(), $crate::Error<I2C::Error>> {
+                self.set_config(state.config) $($await)* ?;
+                self.write_register16($crate::Register::Thyst, state.thyst) $($await)* ?;
+                self.write_register16($crate::Register::Tos, state.tos) $($await)*
+            }
+
+            /// Re-reads the configuration and threshold registers and compares them against the values
+            /// most recently written by the driver, e.g., as a periodic self-test.
+            /// A mismatch indicates that the device was reset (e.g., by a brown-out) or written by something else.
+            /// See [`HealthReport`](crate::HealthReport) for details.
+            ///
+            /// Recovery is left to the caller, e.g., by calling [`Self::restore_state`] or reconstructing the driver.
+            pub $($async)* fn health_check(&mut self) -> Result<$crate::HealthReport, $crate::Error<I2C::Error>> {
+                let state = self.save_state() $($await)* ?;
+                Ok($crate::HealthReport {
+                    state,
+                    config_ok: self.config.map(|x| x == state.config),
+                    thyst_ok: self.thyst.map(|x| x == state.thyst),
+                    tos_ok: self.tos.map(|x| x == state.tos),
+                })
+            }
+
+            /// Reads the raw value of a register.
+            /// The 8-bit [`Register::Config`](crate::Register::Config) is returned in the low byte.
+            ///
+            /// This is a low-level escape hatch intended for bring-up and debugging;
+            /// prefer the typed accessors where possible.
+            pub $($async)* fn read_register(&mut self, reg: $crate::Register) -> Result<u16, $crate::Error<I2C::Error>> {
+                match reg {
+                    $crate::Register::Config => Ok(self.get_config() $($await)* ?.bits() as u16),
+                    _ => Ok(self.read_register16(reg) $($await)* ? as u16),
+                }
+            }
+            /// Writes the raw value of a register.
+            /// The 8-bit [`Register::Config`](crate::Register::Config) takes its value from the low byte,
+            /// and returns [`Error::OutOfRange`](crate::Error::OutOfRange) if the high byte is nonzero.
+            /// Writes to the read-only [`Register::Temp`](crate::Register::Temp) are ignored by the device.
+            ///
+            /// This is a low-level escape hatch intended for bring-up and debugging;
+            /// prefer the typed accessors where possible.
+            pub $($async)* fn write_register(&mut self, reg: $crate::Register, value: u16) -> Result<(), $crate::Error<I2C::Error>> {
+                match reg {
+                    $crate::Register::Config => match u8::try_from(value) {
+                        Ok(bits) => self.set_config($crate::Config::from_bits(bits)) $($await)*,
+                        Err(_) => Err($crate::Error::OutOfRange),
+                    },
+                    _ => self.write_register16(reg, value as i16) $($await)*,
+                }
+            }
+
+            /// Reads the raw contents of every register, which is useful for logging the full device state.
+            /// See [`RegisterDump`](crate::RegisterDump) for details.
+            pub $($async)* fn dump_registers(&mut self) -> Result<$crate::RegisterDump, $crate::Error<I2C::Error>> {
+                Ok($crate::RegisterDump {
+                    temp: self.read_register($crate::Register::Temp) $($await)* ?,
+                    config: self.read_register($crate::Register::Config) $($await)* ? as u8,
+                    thyst: self.read_register($crate::Register::Thyst) $($await)* ?,
+                    tos: self.read_register($crate::Register::Tos) $($await)* ?,
+                })
+            }
+
+            /// Sets the overtemperature shutdown threshold (in Celsius).
+            /// When the temperature exceeds this value, the OS output is asserted.
+            ///
+            /// The value is rounded to the nearest representable step of 1/256 °C.
+            /// Returns [`Error::OutOfRange`](crate::Error::OutOfRange) if the value cannot be represented in the current [`DataFormat`](crate::DataFormat).
+            /// See [`Self::set_thyst`] for the matching hysteresis threshold.
+            pub $($async)* fn set_tos(&mut self, celsius: f64) -> Result<(), $crate::Error<I2C::Error>> {
+                let raw = $crate::codec::encode_celsius(celsius, self.format).and_then(|x| self.uncalibrate(x)).ok_or($crate::Error::OutOfRange)?;
+                self.write_register16($crate::Register::Tos, raw) $($await)*
+            }
+            /// Sets the hysteresis threshold (in Celsius).
+            /// Once the OS output has been asserted, it is deasserted when the temperature falls below this value.
+            ///
+            /// The value is rounded to the nearest representable step of 1/256 °C.
+            /// Returns [`Error::OutOfRange`](crate::Error::OutOfRange) if the value cannot be represented in the current [`DataFormat`](crate::DataFormat).
+            /// See [`Self::set_tos`] for the matching overtemperature threshold.
+            pub $($async)* fn set_thyst(&mut self, celsius: f64) -> Result<(), $crate::Error<I2C::Error>> {
+                let raw = $crate::codec::encode_celsius(celsius, self.format).and_then(|x| self.uncalibrate(x)).ok_or($crate::Error::OutOfRange)?;
+                self.write_register16($crate::Register::Thyst, raw) $($await)*
+            }
+
+            /// Gets the currently programmed overtemperature shutdown threshold (in Celsius). See [`Self::set_tos`] for details.
+            pub $($async)* fn get_tos(&mut self) -> Result<$crate::Temperature, $crate::Error<I2C::Error>> {
+                let raw = self.read_register16($crate::Register::Tos) $($await)* ?;
+                Ok($crate::FromRaw::from_raw(self.calibrate(raw), self.format))
+            }
+            /// Gets the currently programmed hysteresis threshold (in Celsius). See [`Self::set_thyst`] for details.
+            pub $($async)* fn get_thyst(&mut self) -> Result<$crate::Temperature, $crate::Error<I2C::Error>> {
+                let raw = self.read_register16($crate::Register::Thyst) $($await)* ?;
+                Ok($crate::FromRaw::from_raw(self.calibrate(raw), self.format))
+            }
+
+            /// Puts the device into power saving mode, then gets the bus back. See [`Self::release`] for details.
+            /// If powering down fails, the driver is returned along with the error, so that it can be retried or released anyway.
+            pub $($async)* fn power_down_and_release(mut self) -> Result<I2C, (Self, $crate::Error<I2C::Error>)> {
+                match self.power_down() $($await)* {
+                    Ok(()) => Ok(self.core.into_inner()),
+                    Err(e) => Err((self, e)),
+                }
+            }
+            /// Transitions the device into power saving mode.
+            /// In power saving mode, the device will not update its stored temperature,
+            /// meaning subsequent calls to [`Self::get_temperature`] will return the same value.
+            ///
+            /// You may use [`Self::power_up`] to exit power saving mode and resume continuous updates,
+            /// or [`Self::update_once`] to get on-demand temperature updates while staying in power saving mode.
+            pub $($async)* fn power_down(&mut self) -> Result<(), $crate::Error<I2C::Error>> {
+                self.modify_config(|c| c.shutdown = true) $($await)*
+            }
+            /// Exits power saving mode and resumes continuous temperature updates. See [`Self::power_down`] for details.
+            pub $($async)* fn power_up(&mut self) -> Result<(), $crate::Error<I2C::Error>> {
+                self.modify_config(|c| c.shutdown = false) $($await)*
+            }
+            /// Performs a single temperature update while in power saving mode.
+            /// When not in power saving mode, this has no effect.
+            /// See [`Self::power_down`] for more details.
+            pub $($async)* fn update_once(&mut self) -> Result<(), $crate::Error<I2C::Error>> {
+                self.modify_config(|c| c.one_shot = true) $($await)*
+            }
+            /// Checks whether the device is currently in power saving mode. See [`Self::power_down`] for details.
+            pub $($async)* fn is_powered_down(&mut self) -> Result<bool, $crate::Error<I2C::Error>> {
+                Ok(self.get_config() $($await)* ?.shutdown)
+            }
+            /// Checks whether a one-shot conversion started by [`Self::update_once`] is still pending.
+            /// This is the inverse of [`Self::is_conversion_done`].
+            pub $($async)* fn is_one_shot_pending(&mut self) -> Result<bool, $crate::Error<I2C::Error>> {
+                Ok(self.get_config() $($await)* ?.one_shot)
+            }
+            /// Checks whether a one-shot conversion started by [`Self::update_once`] has finished.
+            /// The device clears the one-shot bit once the new temperature is available,
+            /// so this can be polled instead of waiting for the worst-case conversion time.
+            pub $($async)* fn is_conversion_done(&mut self) -> Result<bool, $crate::Error<I2C::Error>> {
+                Ok(!self.get_config() $($await)* ?.one_shot)
+            }
+            /// Performs a one-shot conversion and returns the fresh temperature reading.
+            /// This combines [`Self::update_once`], waiting for the datasheet worst-case conversion time,
+            /// and [`Self::get_temperature`], which is the common pattern for low-power usage in power saving mode.
+            pub $($async)* fn read_one_shot(&mut self, delay: &mut impl $delay) -> Result<$crate::Temperature, $crate::Error<I2C::Error>> {
+                self.update_once() $($await)* ?;
+                delay.delay_ms($crate::CONVERSION_TIME_MS) $($await)*;
+                self.get_temperature() $($await)*
+            }
+
+            /// Sets the operating mode of the OS output pin. See [`OsMode`](crate::OsMode) for details.
+            pub $($async)* fn set_os_mode(&mut self, mode: $crate::OsMode) -> Result<(), $crate::Error<I2C::Error>> {
+                self.modify_config(|c| c.os_mode = mode) $($await)*
+            }
+            /// Gets the current operating mode of the OS output pin. See [`Self::set_os_mode`] for details.
+            pub $($async)* fn os_mode(&mut self) -> Result<$crate::OsMode, $crate::Error<I2C::Error>> {
+                Ok(self.get_config() $($await)* ?.os_mode)
+            }
+
+            /// Sets the active level of the OS output pin. See [`Polarity`](crate::Polarity) for details.
+            pub $($async)* fn set_os_polarity(&mut self, polarity: $crate::Polarity) -> Result<(), $crate::Error<I2C::Error>> {
+                self.modify_config(|c| c.os_polarity = polarity) $($await)*
+            }
+            /// Gets the current active level of the OS output pin. See [`Self::set_os_polarity`] for details.
+            pub $($async)* fn os_polarity(&mut self) -> Result<$crate::Polarity, $crate::Error<I2C::Error>> {
+                Ok(self.get_config() $($await)* ?.os_polarity)
+            }
+
+            /// Sets the fault queue length used to filter the OS output. See [`FaultQueue`](crate::FaultQueue) for details.
+            pub $($async)* fn set_fault_queue(&mut self, queue: $crate::FaultQueue) -> Result<(), $crate::Error<I2C::Error>> {
+                self.modify_config(|c| c.fault_queue = queue) $($await)*
+            }
+            /// Gets the current fault queue length. See [`Self::set_fault_queue`] for details.
+            pub $($async)* fn fault_queue(&mut self) -> Result<$crate::FaultQueue, $crate::Error<I2C::Error>> {
+                Ok(self.get_config() $($await)* ?.fault_queue)
+            }
+
+            /// Enables or disables the I2C bus timeout.
+            /// When enabled (the power-on default), the device resets its bus interface if SDA is held low for too long,
+            /// which prevents a stuck transaction from locking up the bus.
+            /// Hosts that stretch the clock for long periods may need to disable it.
+            pub $($async)* fn set_bus_timeout(&mut self, enabled: bool) -> Result<(), $crate::Error<I2C::Error>> {
+                self.modify_config(|c| c.bus_timeout = enabled) $($await)*
+            }
+            /// Checks whether the I2C bus timeout is enabled. See [`Self::set_bus_timeout`] for details.
+            pub $($async)* fn bus_timeout(&mut self) -> Result<bool, $crate::Error<I2C::Error>> {
+                Ok(self.get_config() $($await)* ?.bus_timeout)
+            }
+
+            /// Sets the data format used by the temperature and threshold registers. See [`DataFormat`](crate::DataFormat) for details.
+            ///
+            /// The driver automatically encodes and decodes values in the selected format.
+            /// However, the device does not convert the stored threshold registers,
+            /// so they should be reprogrammed after changing the format.
+            pub $($async)* fn set_data_format(&mut self, format: $crate::DataFormat) -> Result<(), $crate::Error<I2C::Error>> {
+                self.modify_config(|c| c.data_format = format) $($await)*
+            }
+            /// Gets the data format used by the temperature and threshold registers. See [`Self::set_data_format`] for details.
+            ///
+            /// This reads the format from the device and updates the format used by the driver to match.
+            pub $($async)* fn data_format(&mut self) -> Result<$crate::DataFormat, $crate::Error<I2C::Error>> {
+                Ok(self.get_config() $($await)* ?.data_format)
+            }
+
+            /// Enables or disables plausibility checks on temperature readings.
+            /// While enabled, any reading outside the given inclusive range (in Celsius) fails with
+            /// [`Error::ImplausibleReading`](crate::Error::ImplausibleReading) instead of being returned,
+            /// e.g., to catch a detached probe or wildly wrong values.
+            ///
+            /// The bounds are rounded to the nearest step of 1/256 °C.
+            /// Returns [`Error::OutOfRange`](crate::Error::OutOfRange), leaving the previous range in effect,
+            /// if either bound is NaN or the lower bound exceeds the upper bound.
+            ///
+            /// [`SPECIFIED_RANGE`](crate::SPECIFIED_RANGE) is a reasonable choice for most applications.
+            /// Note that it includes 0 °C, which is what a data line shorted to ground reads in the normal data format,
+            /// so see [`Self::set_reject_stuck_readings`] to catch that as well.
+            /// Disabled by default.
+            pub fn set_plausible_range(&mut self, range: Option<(f64, f64)>) -> Result<(), $crate::Error<I2C::Error>> {
+                self.plausible = match range {
+                    Some((lo, hi)) if lo <= hi => Some(($crate::Temperature::from_celsius(lo).as_raw(), $crate::Temperature::from_celsius(hi).as_raw())),
+                    Some(_) => return Err($crate::Error::OutOfRange), // inverted, or NaN
+                    None => None,
+                };
+                Ok(())
+            }
+            /// Enables or disables rejecting readings whose register is all zeros or all ones (0x0000 or 0xffff),
+            /// which is what a read returns when the data line is stuck low (e.g., shorted to ground) or high.
+            /// While enabled, such readings fail with [`Error::ImplausibleReading`](crate::Error::ImplausibleReading).
+            ///
+            /// These are also valid readings (e.g., exactly 0 °C in the normal data format),
+            /// so this is best combined with [`Self::set_plausible_range`] in applications that never see them.
+            /// Disabled by default.
+            pub fn set_reject_stuck_readings(&mut self, enabled: bool) {
+                self.reject_stuck = enabled;
+            }
+
+            /// Enables stale reading detection after `limit` consecutive identical readings (or disables it if zero, the default).
+            ///
+            /// If the device stops converting (e.g., it silently entered shutdown, or a one-shot was used incorrectly),
+            /// every reading returns the same frozen value. While enabled, [`Self::is_stale`] reports when `limit`
+            /// consecutive readings were identical while the driver believes the device is converting continuously.
+            /// Readings taken while the driver's configuration says the device is powered down are not counted,
+            /// nor are readings from a driver that has not yet written a configuration (see [`Self::refresh_config`]).
+            ///
+            /// A stable environment can legitimately produce identical readings, so `limit` should be chosen
+            /// with the reading interval and expected noise in mind.
+            pub fn set_stale_limit(&mut self, limit: u8) {
+                self.stale_limit = limit;
+                self.repeats = 0;
+            }
+            /// Checks whether the recent readings look frozen. See [`Self::set_stale_limit`] for details.
+            pub fn is_stale(&self) -> bool {
+                self.stale_limit != 0 && self.repeats >= self.stale_limit
+            }
+
+            /// Sets a linear calibration, e.g., as computed by [`Calibration::from_points`](crate::Calibration::from_points)
+            /// from measurements against reference baths. See [`Calibration`](crate::Calibration) for details.
+            ///
+            /// The correction is applied to every temperature reading, and inverted for thresholds when they are programmed
+            /// (and reapplied when they are read), so the OS output trips at the calibrated temperature.
+            /// Thresholds programmed before the calibration was changed are not updated.
+            ///
+            /// The offset is rounded to the nearest step of 1/256 °C, and the gain to the nearest step of 2^-16.
+            /// Returns [`Error::OutOfRange`](crate::Error::OutOfRange) if the offset is not within ±128 °C,
+            /// or the gain is not positive and less than 32768.
+            pub fn set_calibration(&mut self, calibration: $crate::Calibration) -> Result<(), $crate::Error<I2C::Error>> {
+                let gain = $crate::codec::round_for_cast(calibration.gain * 65536.0);
+                if !(gain >= 1.0 && gain < i32::MAX as f64) { return Err($crate::Error::OutOfRange) }
+                self.offset = $crate::codec::encode_celsius(calibration.offset, $crate::DataFormat::Normal).ok_or($crate::Error::OutOfRange)?;
+                self.gain = gain as i32;
+                Ok(())
+            }
+            /// Sets an offset-only calibration (in Celsius). See [`Self::set_calibration`] for details.
+            pub fn set_calibration_offset(&mut self, celsius: f64) -> Result<(), $crate::Error<I2C::Error>> {
+                self.set_calibration($crate::Calibration::from_offset(celsius))
+            }
+            /// Gets the calibration in effect, after rounding. See [`Self::set_calibration`] for details.
+            pub fn calibration(&self) -> $crate::Calibration {
+                $crate::Calibration {
+                    gain: self.gain as f64 / 65536.0,
+                    offset: $crate::codec::decode_celsius(self.offset, $crate::DataFormat::Normal),
+                }
+            }
+            /// Enables (or disables, if `None`, the default) compensation for self-heating of the device.
+            /// See [`SelfHeating`](crate::SelfHeating) for details.
+            ///
+            /// Like the calibration set by [`Self::set_calibration`], the compensation is applied to readings and thresholds.
+            /// The model is not updated automatically, so it should be set again whenever the sampling pattern changes.
+            pub fn set_self_heating(&mut self, model: Option<$crate::SelfHeating>) {
+                self.heating = model.map_or(0, |m| $crate::codec::millicelsius_to_lsb(m.error_mk() as i64) as i16);
+            }
+            // converts a register value to a calibrated one, saturating at the ends of the register range
+            fn calibrate(&self, raw: i16) -> i16 {
+                let format = self.format.offset() as i64;
+                let t = ((raw as i64 + format) * self.gain as i64 + (1 << 15)) >> 16;
+                (t + self.offset as i64 - self.heating as i64 - format).clamp(i16::MIN as i64, i16::MAX as i64) as i16
+            }
+            // converts a calibrated value to a register value, or None if it is not representable
+            fn uncalibrate(&self, value: i16) -> Option<i16> {
+                let format = self.format.offset() as i64;
+                let num = (value as i64 + format - self.offset as i64 + self.heating as i64) << 16;
+                let t = $crate::codec::div_round(num as i128, self.gain as i128) as i64;
+                i16::try_from(t - format).ok()
+            }
+
+            /// Gets an instantaneous raw temperature reading from the device.
+            /// This is the signed register value, where each LSB is 1/256 °C,
+            /// relative to the offset defined by the current [`DataFormat`](crate::DataFormat).
+            ///
+            /// This is exactly the register contents, e.g., for logging readings to be decoded on a host (see [`codec`](crate::codec)).
+            /// Unlike the decoded readers (e.g., [`Self::get_temperature`]), it does not include the corrections set by
+            /// [`Self::set_calibration`] and [`Self::set_self_heating`], and is not subject to the plausibility checks enabled by
+            /// [`Self::set_plausible_range`] and [`Self::set_reject_stuck_readings`]. It is still tracked by the stale reading detection enabled by [`Self::set_stale_limit`].
+            pub $($async)* fn get_temperature_raw(&mut self) -> Result<i16, $crate::Error<I2C::Error>> {
+                let raw = self.read_register16($crate::Register::Temp) $($await)* ?;
+                if self.config.is_some_and(|c| !c.shutdown) {
+                    self.repeats = if raw == self.last_raw { self.repeats.saturating_add(1) } else { 1 };
+                    self.last_raw = raw;
+                } else {
+                    self.repeats = 0;
+                }
+                Ok(raw)
+            }
+            // reads the temperature register with calibration, self-heating compensation, and plausibility checks applied
+            $($async)* fn get_temperature_corrected(&mut self) -> Result<i16, $crate::Error<I2C::Error>> {
+                let raw = self.get_temperature_raw() $($await)* ?;
+                if self.reject_stuck && (raw == 0 || raw == -1) { return Err($crate::Error::ImplausibleReading) }
+                let raw = self.calibrate(raw);
+                if let Some((lo, hi)) = self.plausible {
+                    if !(lo..=hi).contains(&(raw as i32 + self.format.offset())) { return Err($crate::Error::ImplausibleReading) }
+                }
+                Ok(raw)
+            }
+            /// Gets a temperature reading as a compact [`Measurement`](crate::Measurement) tagged with the given sequence number,
+            /// e.g., for sending over a radio link.
+            /// Like [`Self::get_temperature`], this applies any calibration and plausibility checks.
+            pub $($async)* fn get_measurement(&mut self, sequence: u16) -> Result<$crate::Measurement, $crate::Error<I2C::Error>> {
+                let raw = self.get_temperature_corrected() $($await)* ?;
+                Ok($crate::Measurement::new(raw, self.format, self.is_stale(), sequence))
+            }
+            /// Gets the average of `count` (at least one) consecutive conversions, waiting for the worst-case conversion time between readings,
+            /// which reduces noise at the cost of taking about `count * 50` ms. The device must be converting continuously.
+            /// See [`filters::Decimator`](crate::filters::Decimator) for decimating readings taken elsewhere.
+            pub $($async)* fn read_averaged(&mut self, delay: &mut impl $delay, count: u16) -> Result<$crate::Temperature, $crate::Error<I2C::Error>> {
+                let count = count.max(1) as i128;
+                let mut sum = 0;
+                for i in 0..count {
+                    if i != 0 { delay.delay_ms($crate::CONVERSION_TIME_MS) $($await)* }
+                    sum += self.get_temperature() $($await)* ?.as_raw() as i128;
+                }
+                Ok($crate::Temperature::from_raw($crate::codec::div_round(sum, count) as i32))
+            }
+            /// Gets an instantaneous temperature reading from the device, decoded into any [`FromRaw`](crate::FromRaw) type.
+            /// The representation can be chosen once via a type annotation, e.g., `let t: f32 = sensor.get_temperature_as()?;` (in Celsius).
+            pub $($async)* fn get_temperature_as<T: $crate::FromRaw>(&mut self) -> Result<T, $crate::Error<I2C::Error>> {
+                Ok(T::from_raw(self.get_temperature_corrected() $($await)* ?, self.format))
+            }
+            /// Gets an instantaneous temperature reading from the device. See [`Temperature`](crate::Temperature) for details.
+            pub $($async)* fn get_temperature(&mut self) -> Result<$crate::Temperature, $crate::Error<I2C::Error>> {
+                self.get_temperature_as() $($await)*
+            }
+            /// Gets an instantaneous temperature reading (in Celsius) from the device as an [`f32`].
+            /// This avoids double-precision math on targets where it is expensive, while still exceeding the device resolution.
+            pub $($async)* fn get_temperature_f32(&mut self) -> Result<f32, $crate::Error<I2C::Error>> {
+                self.get_temperature_as() $($await)*
+            }
+            /// Gets an instantaneous temperature reading (in Celsius) from the device as a fixed-point value.
+            /// This conversion is exact, as the device resolution is a power of two.
+            #[cfg(feature = "fixed")]
+            pub $($async)* fn get_temperature_fixed(&mut self) -> Result<fixed::types::I16F16, $crate::Error<I2C::Error>> {
+                self.get_temperature_as() $($await)*
+            }
+            /// Gets an instantaneous temperature reading from the device as a `uom` quantity, for use with dimensional analysis.
+            #[cfg(feature = "uom")]
+            pub $($async)* fn get_temperature_uom(&mut self) -> Result<uom::si::f64::ThermodynamicTemperature, $crate::Error<I2C::Error>> {
+                self.get_temperature_as() $($await)*
+            }
+            /// Gets an instantaneous temperature reading (in thousandths of a degree Celsius) from the device.
+            /// This uses only integer math, so it can be used to avoid floating point entirely.
+            pub $($async)* fn get_temperature_millicelsius(&mut self) -> Result<i32, $crate::Error<I2C::Error>> {
+                Ok($crate::codec::decode_millicelsius(self.get_temperature_corrected() $($await)* ?, self.format))
+            }
+        }
+    };
+}
@@ -0,0 +1,126 @@
+use crate::Register;
+
+/// The direction of a failed register access, as reported by [`Error::Register`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Access {
+    /// The register was being read.
+    Read,
+    /// The register was being written.
+    Write,
+}
+
+impl Access {
+    fn verb(self) -> &'static str {
+        match self {
+            Access::Read => "reading",
+            Access::Write => "writing",
+        }
+    }
+}
+
+/// An error produced by the MAX30205 driver.
+///
+/// `E` is the error type of the underlying I2C bus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Error<E> {
+    /// The underlying I2C bus reported an error outside of any register access (e.g., while scanning).
+    Bus(E),
+    /// The underlying I2C bus reported an error while accessing a register of the device.
+    Register {
+        /// The register being accessed.
+        register: Register,
+        /// Whether the register was being read or written.
+        access: Access,
+        /// The bus error.
+        error: E,
+    },
+    /// No device responded at any of the probed addresses.
+    NoDevice,
+    /// A provided value is outside the range the device can represent.
+    OutOfRange,
+    /// The device returned data that could not be interpreted.
+    InvalidData,
+    /// The underlying I2C bus reported an error while accessing a register of the device,
+    /// after which the driver automatically re-initialized the device.
+    /// The failed operation was not performed, but can be retried.
+    /// See [`MAX30205::set_auto_recovery`](crate::MAX30205::set_auto_recovery) for details.
+    Recovered {
+        /// The register being accessed.
+        register: Register,
+        /// Whether the register was being read or written.
+        access: Access,
+        /// The bus error.
+        error: E,
+    },
+    /// A temperature reading was outside the configured plausible range.
+    /// See [`MAX30205::set_plausible_range`](crate::MAX30205::set_plausible_range) for details.
+    ImplausibleReading,
+    /// The operation did not complete within the allotted time.
+    #[cfg_attr(feature = "async", doc = "See [`with_timeout`](crate::with_timeout) for details.")]
+    Timeout,
+    /// A GPIO pin reported an error, e.g., during bus recovery (see [`recover_bus`](crate::recover_bus)).
+    Pin(#[cfg_attr(feature = "serde", serde(with = "pin_error_kind"))] embedded_hal::digital::ErrorKind),
+}
+#[cfg(feature = "serde")]
+mod pin_error_kind {
+    use embedded_hal::digital::ErrorKind;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    // `ErrorKind::Other` is the only kind, so there is nothing to store
+    pub fn serialize<S: Serializer>(_: &ErrorKind, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit()
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ErrorKind, D::Error> {
+        <()>::deserialize(deserializer).map(|()| ErrorKind::Other)
+    }
+}
+
+impl<E> From<E> for Error<E> {
+    fn from(e: E) -> Self {
+        Error::Bus(e)
+    }
+}
+impl<E> Error<E> {
+    /// Gets the underlying bus error, if any.
+    pub fn bus_error(&self) -> Option<&E> {
+        match self {
+            Error::Bus(e) | Error::Register { error: e, .. } | Error::Recovered { error: e, .. } => Some(e),
+            _ => None,
+        }
+    }
+    /// Converts the bus error (if any) with the given function, leaving driver-level errors unchanged.
+    pub fn map_bus<F>(self, f: impl FnOnce(E) -> F) -> Error<F> {
+        match self {
+            Error::Bus(e) => Error::Bus(f(e)),
+            Error::Register { register, access, error } => Error::Register { register, access, error: f(error) },
+            Error::NoDevice => Error::NoDevice,
+            Error::OutOfRange => Error::OutOfRange,
+            Error::InvalidData => Error::InvalidData,
+            Error::Recovered { register, access, error } => Error::Recovered { register, access, error: f(error) },
+            Error::ImplausibleReading => Error::ImplausibleReading,
+            Error::Timeout => Error::Timeout,
+            Error::Pin(e) => Error::Pin(e),
+        }
+    }
+}
+impl<E: core::fmt::Debug> core::fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Bus(e) => write!(f, "I2C bus error: {e:?}"),
+            Error::Register { register, access, error } => write!(f, "I2C bus error while {} the {register:?} register: {error:?}", access.verb()),
+            Error::NoDevice => f.write_str("no device found"),
+            Error::OutOfRange => f.write_str("value out of range"),
+            Error::InvalidData => f.write_str("invalid data received from device"),
+            Error::Recovered { register, access, error } => write!(f, "I2C bus error while {} the {register:?} register (device re-initialized): {error:?}", access.verb()),
+            Error::ImplausibleReading => f.write_str("implausible temperature reading"),
+            Error::Timeout => f.write_str("operation timed out"),
+            Error::Pin(e) => write!(f, "GPIO pin error: {e:?}"),
+        }
+    }
+}
+impl<E: core::fmt::Debug> core::error::Error for Error<E> {}
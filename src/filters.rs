@@ -0,0 +1,281 @@
+//! Filters for smoothing and cleaning up temperature readings.
+//!
+//! Filters operate on readings in thousandths of a degree Celsius (see [`MAX30205::get_temperature_millicelsius`]),
+//! so they use only integer math. They can be fed readings directly via [`Filter::update`],
+//! wrap a driver via [`Filtered`], or be used wherever a closure over [`Temperature`] readings is expected via [`temperature_fn`].
+
+use embedded_hal::i2c::I2c;
+
+use crate::codec::div_round;
+use crate::{MAX30205, Temperature, Error};
+
+/// A filter over a sequence of temperature readings (in thousandths of a degree Celsius).
+pub trait Filter {
+    /// Feeds a new reading into the filter, returning the filtered value.
+    fn update(&mut self, millicelsius: i32) -> i32;
+    /// Discards all previous readings, returning the filter to its initial state.
+    fn reset(&mut self);
+    /// Feeds a new [`Temperature`] reading into the filter, returning the filtered value (rounded to the nearest 1/256 °C).
+    fn update_temperature(&mut self, temperature: Temperature) -> Temperature {
+        Temperature::from_millicelsius(self.update(temperature.as_millicelsius()))
+    }
+}
+
+/// Converts a filter into a closure over [`Temperature`] readings, e.g., for the async `Sampler::with_filter` (with the `embassy` feature):
+///
+/// ```ignore
+/// let sampler = Sampler::new(sensor, Duration::from_secs(1)).with_filter(filters::temperature_fn(MovingAverage::<8>::new()));
+/// ```
+pub fn temperature_fn<F: Filter>(mut filter: F) -> impl FnMut(Temperature) -> Temperature {
+    move |temperature| filter.update_temperature(temperature)
+}
+
+/// A driver whose readings are passed through a [`Filter`].
+pub struct Filtered<I2C, F> {
+    sensor: MAX30205<I2C>,
+    filter: F,
+}
+impl<I2C, F: Filter> Filtered<I2C, F> {
+    /// Wraps a driver with the given filter.
+    pub fn new(sensor: MAX30205<I2C>, filter: F) -> Self {
+        Self { sensor, filter }
+    }
+    /// Gets the wrapped sensor, e.g., to change its configuration.
+    pub fn sensor(&mut self) -> &mut MAX30205<I2C> {
+        &mut self.sensor
+    }
+    /// Gets the filter, e.g., to reset it.
+    pub fn filter(&mut self) -> &mut F {
+        &mut self.filter
+    }
+    /// Gets the wrapped sensor and filter back.
+    pub fn into_inner(self) -> (MAX30205<I2C>, F) {
+        (self.sensor, self.filter)
+    }
+}
+impl<I2C: I2c, F: Filter> Filtered<I2C, F> {
+    /// Takes a reading and feeds it through the filter, returning the filtered value (in thousandths of a degree Celsius).
+    /// Failed readings are not fed to the filter.
+    pub fn get_temperature_millicelsius(&mut self) -> Result<i32, Error<I2C::Error>> {
+        Ok(self.filter.update(self.sensor.get_temperature_millicelsius()?))
+    }
+}
+
+/// A moving average over the last `N` readings.
+///
+/// Until `N` readings have been seen, the average is over the readings seen so far.
+#[derive(Debug, Clone)]
+pub struct MovingAverage<const N: usize> {
+    window: [i32; N],
+    next: usize,
+    len: usize,
+    sum: i64,
+}
+impl<const N: usize> MovingAverage<N> {
+    const NONEMPTY: () = assert!(N > 0, "moving average window must not be empty");
+
+    /// Constructs an empty moving average.
+    pub const fn new() -> Self {
+        let () = Self::NONEMPTY;
+        Self { window: [0; N], next: 0, len: 0, sum: 0 }
+    }
+    /// Gets the current average, or `None` if no readings have been seen.
+    pub fn value(&self) -> Option<i32> {
+        if self.len == 0 { None } else { Some(div_round(self.sum as i128, self.len as i128) as i32) }
+    }
+}
+impl<const N: usize> Default for MovingAverage<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<const N: usize> Filter for MovingAverage<N> {
+    fn update(&mut self, millicelsius: i32) -> i32 {
+        if self.len == N {
+            self.sum -= self.window[self.next] as i64;
+        } else {
+            self.len += 1;
+        }
+        self.window[self.next] = millicelsius;
+        self.sum += millicelsius as i64;
+        self.next = (self.next + 1) % N;
+        div_round(self.sum as i128, self.len as i128) as i32
+    }
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// A single-pole IIR (exponential moving average) filter, which uses constant memory regardless of its time constant.
+///
+/// Each reading moves the output towards it by a fraction `alpha` of the difference, so smaller values give smoother (but slower) output.
+/// The first reading initializes the output directly.
+#[derive(Debug, Clone, Copy)]
+pub struct Exponential {
+    alpha: i64,
+    state: Option<i64>, // with 16 fractional bits, so small steps are not lost to rounding
+}
+impl Exponential {
+    /// Constructs a filter with the given `alpha`, in units of 1/65536 (e.g., `6554` is about 0.1).
+    /// Zero is treated as the smallest nonzero step, so that the output still follows the input.
+    pub const fn new(alpha: u16) -> Self {
+        Self { alpha: if alpha == 0 { 1 } else { alpha as i64 }, state: None }
+    }
+    /// Gets the current output, or `None` if no readings have been seen.
+    pub fn value(&self) -> Option<i32> {
+        self.state.map(|x| ((x + (1 << 15)) >> 16) as i32)
+    }
+}
+impl Filter for Exponential {
+    fn update(&mut self, millicelsius: i32) -> i32 {
+        let x = (millicelsius as i64) << 16;
+        self.state = Some(match self.state {
+            Some(y) => y + ((self.alpha * (x - y)) >> 16),
+            None => x,
+        });
+        self.value().unwrap()
+    }
+    fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+/// A median filter over the last `N` readings, which rejects isolated spikes (e.g., from EMI) entirely
+/// rather than averaging them in. A window of `N` rejects up to `(N - 1) / 2` consecutive outliers,
+/// at the cost of delaying genuine steps by about `N / 2` readings.
+///
+/// Until `N` readings have been seen, the median is over the readings seen so far.
+/// For an even number of readings, the mean of the middle two is used.
+#[derive(Debug, Clone)]
+pub struct Median<const N: usize> {
+    window: [i32; N],
+    next: usize,
+    len: usize,
+}
+impl<const N: usize> Median<N> {
+    const NONEMPTY: () = assert!(N > 0, "median window must not be empty");
+
+    /// Constructs an empty median filter.
+    pub const fn new() -> Self {
+        let () = Self::NONEMPTY;
+        Self { window: [0; N], next: 0, len: 0 }
+    }
+    /// Gets the current median, or `None` if no readings have been seen.
+    pub fn value(&self) -> Option<i32> {
+        if self.len == 0 { return None }
+        let mut sorted = self.window;
+        let sorted = &mut sorted[..self.len];
+        sorted.sort_unstable();
+        let mid = self.len / 2;
+        Some(if self.len % 2 == 1 { sorted[mid] } else { div_round(sorted[mid - 1] as i128 + sorted[mid] as i128, 2) as i32 })
+    }
+}
+impl<const N: usize> Default for Median<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<const N: usize> Filter for Median<N> {
+    fn update(&mut self, millicelsius: i32) -> i32 {
+        self.window[self.next] = millicelsius;
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+        self.value().unwrap()
+    }
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// A decimator which averages every `N` readings into a single output, e.g., to turn the roughly 20 Hz conversions of the device
+/// into 1 Hz data with reduced noise. Unlike [`MovingAverage`], this produces one output per `N` inputs.
+/// See [`MAX30205::read_averaged`] for reading a decimated sample directly from the device.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Decimator<const N: usize> {
+    sum: i64,
+    count: usize,
+}
+impl<const N: usize> Decimator<N> {
+    const NONEMPTY: () = assert!(N > 0, "decimation factor must not be zero");
+
+    /// Constructs an empty decimator.
+    pub const fn new() -> Self {
+        let () = Self::NONEMPTY;
+        Self { sum: 0, count: 0 }
+    }
+    /// Feeds a new reading, returning the average of the last `N` readings once every `N` readings.
+    pub fn push(&mut self, millicelsius: i32) -> Option<i32> {
+        self.sum += millicelsius as i64;
+        self.count += 1;
+        if self.count < N { return None }
+        let res = div_round(self.sum as i128, N as i128) as i32;
+        *self = Self::new();
+        Some(res)
+    }
+    /// Discards the readings of the current (incomplete) output.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// A one-dimensional Kalman filter, modelling the temperature as a slowly drifting (random walk) value.
+///
+/// Noise is specified as variances in squared thousandths of a degree Celsius. For example, a measurement noise of
+/// `2500` corresponds to a standard deviation of 0.05 °C, and a process noise of `1` lets the true temperature drift by
+/// about 0.001 °C per reading; a small process noise relative to the measurement noise suits slow thermal processes.
+///
+/// As a [`Filter`], each reading is one time step. For fusing several inputs (e.g., the MAX30205 and a second sensor),
+/// call [`Kalman::predict`] once per time step and [`Kalman::correct`] once per available reading, with that input's noise.
+#[cfg(feature = "kalman")]
+#[derive(Debug, Clone, Copy)]
+pub struct Kalman {
+    process_noise: u64,
+    measurement_noise: u64,
+    state: Option<(i64, u64)>, // estimate with 16 fractional bits, and its variance
+}
+#[cfg(feature = "kalman")]
+impl Kalman {
+    /// Constructs a filter with the given process noise (per time step) and measurement noise (of readings passed to [`Filter::update`]).
+    pub const fn new(process_noise: u32, measurement_noise: u32) -> Self {
+        Self { process_noise: process_noise as u64, measurement_noise: measurement_noise as u64, state: None }
+    }
+    /// Gets the current estimate, or `None` if no readings have been seen.
+    pub fn value(&self) -> Option<i32> {
+        self.state.map(|(x, _)| ((x + (1 << 15)) >> 16) as i32)
+    }
+    /// Gets the variance of the current estimate, or `None` if no readings have been seen.
+    pub fn variance(&self) -> Option<u64> {
+        self.state.map(|(_, p)| p)
+    }
+    /// Advances the filter by one time step without a reading, increasing the uncertainty of the estimate.
+    pub fn predict(&mut self) {
+        if let Some((_, p)) = &mut self.state {
+            *p = p.saturating_add(self.process_noise);
+        }
+    }
+    /// Incorporates a reading with the given measurement noise, returning the updated estimate.
+    /// The first reading initializes the estimate directly.
+    pub fn correct(&mut self, millicelsius: i32, measurement_noise: u32) -> i32 {
+        let z = (millicelsius as i64) << 16;
+        let r = measurement_noise as u64;
+        self.state = Some(match self.state {
+            Some((x, p)) if p.saturating_add(r) != 0 => {
+                let s = (p as u128 + r as u128) as i128;
+                let x = x + ((z - x) as i128 * p as i128 / s) as i64;
+                (x, (p as u128 * r as u128 / s as u128) as u64)
+            }
+            _ => (z, r),
+        });
+        self.value().unwrap()
+    }
+}
+#[cfg(feature = "kalman")]
+impl Filter for Kalman {
+    fn update(&mut self, millicelsius: i32) -> i32 {
+        self.predict();
+        self.correct(millicelsius, self.measurement_noise as u32)
+    }
+    fn reset(&mut self) {
+        self.state = None;
+    }
+}
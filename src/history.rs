@@ -0,0 +1,76 @@
+//! A fixed-capacity history of timestamped temperature readings, for post-event analysis on devices without a filesystem.
+
+use embedded_hal::i2c::I2c;
+use heapless::HistoryBuf;
+
+use crate::{MAX30205, Clock, Error};
+
+/// A timestamped temperature reading, as stored by [`HistoryLogger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Record {
+    /// The time of the reading, from a caller-supplied tick source (see [`Clock`]).
+    pub ticks: u32,
+    /// The reading (in thousandths of a degree Celsius).
+    pub millicelsius: i32,
+}
+
+/// A ring buffer of the last `N` timestamped readings. Once full, each new reading overwrites the oldest.
+pub struct HistoryLogger<const N: usize> {
+    records: HistoryBuf<Record, N>,
+}
+impl<const N: usize> HistoryLogger<N> {
+    /// Constructs an empty logger.
+    pub const fn new() -> Self {
+        Self { records: HistoryBuf::new() }
+    }
+    /// Records a reading taken at time `ticks`.
+    pub fn push(&mut self, ticks: u32, millicelsius: i32) {
+        self.records.write(Record { ticks, millicelsius });
+    }
+    /// Takes a reading from the sensor and records it at time `ticks`, returning the reading.
+    /// Failed readings are not recorded.
+    pub fn log<I2C: I2c>(&mut self, sensor: &mut MAX30205<I2C>, ticks: u32) -> Result<i32, Error<I2C::Error>> {
+        let millicelsius = sensor.get_temperature_millicelsius()?;
+        self.push(ticks, millicelsius);
+        Ok(millicelsius)
+    }
+    /// Takes a reading from the sensor and records it with the current time of `clock`, returning the reading.
+    /// Failed readings are not recorded.
+    pub fn log_now<I2C: I2c>(&mut self, sensor: &mut MAX30205<I2C>, clock: &impl Clock) -> Result<i32, Error<I2C::Error>> {
+        self.log(sensor, clock.now())
+    }
+    /// Gets the number of stored records.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+    /// Checks whether no records are stored.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+    /// Gets the newest record, if any.
+    pub fn latest(&self) -> Option<&Record> {
+        self.records.recent()
+    }
+    /// Gets the oldest record, if any.
+    pub fn oldest(&self) -> Option<&Record> {
+        self.records.oldest()
+    }
+    /// Iterates over the stored records, from oldest to newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Record> + Clone + '_ {
+        self.records.oldest_ordered()
+    }
+    /// Iterates over the newest (up to) `n` records, from oldest to newest.
+    pub fn last(&self, n: usize) -> impl Iterator<Item = &Record> + Clone + '_ {
+        self.iter().skip(self.len().saturating_sub(n))
+    }
+    /// Discards all stored records.
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+}
+impl<const N: usize> Default for HistoryLogger<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
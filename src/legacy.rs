@@ -0,0 +1,68 @@
+use embedded_hal::i2c::{ErrorKind, ErrorType, NoAcknowledgeSource, Operation};
+use embedded_hal_02::blocking::i2c::{Write, WriteRead};
+
+/// An adapter that lets an embedded-hal 0.2 I2C bus (implementing `blocking::i2c::Write` and `blocking::i2c::WriteRead`)
+/// be used with any driver in this crate:
+///
+/// ```ignore
+/// let sensor = MAX30205::new(addr, LegacyI2c::new(i2c))?;
+/// ```
+///
+/// The 0.2 traits cannot express arbitrary transactions, so only single writes and writes followed by a single read are supported.
+/// Anything else, such as the bare read done by [`MAX30205::alert_response`](crate::MAX30205::alert_response),
+/// fails with [`LegacyI2cError::Unsupported`].
+///
+/// The 0.2 error types are opaque, so a missing acknowledge cannot be told apart from other bus faults.
+/// Every error from the wrapped bus is therefore reported as [`ErrorKind::NoAcknowledge`], which is the usual cause,
+/// so that [`MAX30205::scan`](crate::MAX30205::scan) and similar probes skip empty addresses instead of failing.
+/// As a consequence, genuine bus faults also look like absent devices.
+pub struct LegacyI2c<I2C> {
+    i2c: I2C,
+}
+impl<I2C> LegacyI2c<I2C> {
+    /// Wraps an embedded-hal 0.2 bus.
+    pub fn new(i2c: I2C) -> Self {
+        Self { i2c }
+    }
+    /// Gets the wrapped bus.
+    pub fn into_inner(self) -> I2C {
+        self.i2c
+    }
+}
+
+/// An error produced by [`LegacyI2c`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum LegacyI2cError<E> {
+    /// The underlying bus reported an error.
+    Bus(E),
+    /// The transaction cannot be expressed with the embedded-hal 0.2 traits.
+    Unsupported,
+}
+impl<E: core::fmt::Debug> embedded_hal::i2c::Error for LegacyI2cError<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            LegacyI2cError::Bus(_) => ErrorKind::NoAcknowledge(NoAcknowledgeSource::Unknown), // see the LegacyI2c docs
+            LegacyI2cError::Unsupported => ErrorKind::Other,
+        }
+    }
+}
+
+impl<I2C, E: core::fmt::Debug> ErrorType for LegacyI2c<I2C> where I2C: Write<Error = E> + WriteRead<Error = E> {
+    type Error = LegacyI2cError<E>;
+}
+impl<I2C, E: core::fmt::Debug> embedded_hal::i2c::I2c for LegacyI2c<I2C> where I2C: Write<Error = E> + WriteRead<Error = E> {
+    fn transaction(&mut self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+        match operations {
+            [Operation::Write(bytes)] => self.write(address, bytes),
+            [Operation::Write(bytes), Operation::Read(buffer)] => self.write_read(address, bytes, buffer),
+            _ => Err(LegacyI2cError::Unsupported),
+        }
+    }
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        Write::write(&mut self.i2c, address, bytes).map_err(LegacyI2cError::Bus)
+    }
+    fn write_read(&mut self, address: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+        WriteRead::write_read(&mut self.i2c, address, bytes, buffer).map_err(LegacyI2cError::Bus)
+    }
+}
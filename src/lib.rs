@@ -3,80 +3,203 @@
 
 #![doc = include_str!("../README.md")]
 
-use embedded_hal::blocking::i2c;
+#[macro_use]
+mod driver;
+mod address;
+mod alert;
+mod array;
+mod auto_shutdown;
+mod builder;
+mod calibration;
+pub mod clinical;
+mod clock;
+pub mod codec;
+mod config;
+mod const_addr;
+pub mod control;
+mod error;
+pub mod filters;
+pub mod history;
+#[cfg(feature = "embedded-hal-02")]
+mod legacy;
+pub mod lm75;
+mod measurement;
+mod multibus;
+#[cfg(feature = "nb")]
+mod nonblocking;
+mod recovery;
+mod retry;
+#[cfg(feature = "critical-section")]
+mod shared;
+#[cfg(feature = "embedded-hal-bus")]
+mod shared_bus;
+#[cfg(feature = "sim")]
+mod sim;
+#[cfg(feature = "embedded-sensors-hal")]
+mod sensors;
+mod state_machine;
+mod temperature;
+pub mod stats;
+#[cfg(feature = "stream")]
+mod stream;
+#[cfg(feature = "embassy")]
+mod sampler;
+mod typestate;
+mod unwrapped;
+#[cfg(feature = "async")]
+mod timeout;
 
+pub use address::{ADDRESSES, ALERT_RESPONSE_ADDRESS, Address, AddressSource, ConstAddress, Pin};
+pub use alert::InterruptDriver;
+#[cfg(feature = "async")]
+pub use alert::AlertDriver;
+pub use array::MAX30205Array;
+pub use auto_shutdown::MAX30205AutoShutdown;
+pub use builder::MAX30205Builder;
+pub use calibration::{Calibration, SelfHeating};
+pub use clock::Clock;
+#[cfg(feature = "embassy")]
+pub use clock::EmbassyClock;
+pub use config::{Config, OsMode, Polarity, FaultQueue, DataFormat, Thresholds, DeviceState, HealthReport};
+pub use const_addr::MAX30205Const;
+pub use error::{Error, Access};
+#[cfg(feature = "embedded-hal-02")]
+pub use legacy::{LegacyI2c, LegacyI2cError};
+pub use measurement::Measurement;
+pub use multibus::{AnyMAX30205, MultiBusManager, SensorId, SensorReading};
+pub use recovery::recover_bus;
+pub use retry::{RetryI2c, NoDelay};
+#[cfg(feature = "embassy")]
+pub use sampler::Sampler;
+#[cfg(feature = "critical-section")]
+pub use shared::SharedMAX30205;
+#[cfg(feature = "embedded-hal-bus")]
+pub use shared_bus::SharedBus;
+#[cfg(feature = "sim")]
+pub use sim::{Profile, SimulatedBus, SimulatedMAX30205};
+pub use state_machine::MAX30205StateMachine;
+pub use temperature::Temperature;
+pub use typestate::{MAX30205Typed, Continuous, Shutdown};
+pub use unwrapped::MAX30205Unwrapped;
+#[cfg(feature = "async")]
+pub use timeout::with_timeout;
+
+/// The registers of the MAX30205 device.
+///
+/// These are only needed for low-level register access, such as [`MAX30205::read_register`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
-enum Register {
+pub enum Register {
+    /// The (read-only) 16-bit temperature register.
     Temp   = 0,
+    /// The 8-bit configuration register.
     Config = 1,
+    /// The 16-bit hysteresis threshold register.
     Thyst  = 2,
+    /// The 16-bit overtemperature shutdown threshold register.
     Tos    = 3,
 }
 
-const ADDRESSES: &'static [u8] = &[0x49, 0x48];
+/// A snapshot of the raw contents of every device register, as returned by [`MAX30205::dump_registers`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegisterDump {
+    /// The raw value of [`Register::Temp`].
+    pub temp: u16,
+    /// The raw value of [`Register::Config`].
+    pub config: u8,
+    /// The raw value of [`Register::Thyst`].
+    pub thyst: u16,
+    /// The raw value of [`Register::Tos`].
+    pub tos: u16,
+}
 
-/// A MAX30205 sensor wrapper.
-pub struct MAX30205<I2C> {
-    i2c: I2C,
-    addr: u8,
+/// Counters of the bus operations performed by a driver, as returned by [`MAX30205::diagnostics`].
+///
+/// Each register access counts as one transaction. All counters wrap on overflow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diagnostics {
+    /// The number of attempted register accesses.
+    pub transactions: u32,
+    /// The number of register accesses that failed with a bus error (including NACKs).
+    pub errors: u32,
+    /// The number of register accesses that failed because the device did not acknowledge.
+    pub nacks: u32,
+    /// The number of times the device was automatically re-initialized. See [`MAX30205::set_auto_recovery`].
+    pub recoveries: u32,
 }
-impl<I2C> MAX30205<I2C> {
-    /// Scans for available devices on the expected set of addresses.
-    /// Returns `Some(addr)` with the first found valid address, or `None` if no devices are found.
-    ///
-    /// Note that a found device is not necessarily a MAX30205 sensor,
-    /// as it could be that some other device has the same address as a MAX30205 device.
-    pub fn scan(i2c: &mut I2C) -> Option<u8> where I2C: i2c::Write<u8> {
-        for addr in ADDRESSES.iter().copied() {
-            if i2c.write(addr, &[]).is_ok() { return Some(addr) }
-        }
-        None
-    }
-    /// Constructs a MAX30205 sensor wrapper targeting the given address.
-    /// If the address is unknown, [`MAX30205::scan`] can be used.
-    ///
-    /// Also initializes the device for usage, which requires the I2C bus for communication.
-    /// The initial state disables power saving mode.
-    /// See [`MAX30205::power_down`] for details.
-    pub fn new<E>(addr: u8, mut i2c: I2C) -> Result<Self, E> where I2C: i2c::Write<u8, Error = E> {
-        i2c.write(addr, &[Register::Config as u8, 0x00])?;
-        i2c.write(addr, &[Register::Thyst  as u8, 0x00])?;
-        i2c.write(addr, &[Register::Tos    as u8, 0x00])?;
-        Ok(Self { i2c, addr })
-    }
 
-    fn transform_config<E>(&mut self, trans: fn(u8) -> u8) -> Result<(), E> where I2C: i2c::WriteRead<u8, Error = E> + i2c::Write<u8, Error = E> {
-        let mut reg = [0u8];
-        self.i2c.write_read(self.addr, &[Register::Config as u8], &mut reg)?;
-        self.i2c.write(self.addr, &[Register::Config as u8, trans(reg[0])])?;
-        Ok(())
-    }
+/// The power-on values of the threshold registers (75 °C and 80 °C in the normal data format).
+const DEFAULT_THYST_RAW: i16 = 0x4b00;
+const DEFAULT_TOS_RAW:   i16 = 0x5000;
+
+/// The temperature range (in Celsius) over which the MAX30205 is specified to operate.
+///
+/// This is a reasonable default for [`MAX30205::set_plausible_range`].
+/// It includes 0 °C, which is also what a shorted data line reads, so see [`MAX30205::set_reject_stuck_readings`] to catch that.
+pub const SPECIFIED_RANGE: (f64, f64) = (0.0, 50.0);
+
+/// The worst-case duration of a single temperature conversion.
+const CONVERSION_TIME_MS: u32 = 50;
 
-    /// Transitions the device into power saving mode.
-    /// In power saving mode, the device will not update its stored temperature,
-    /// meaning subsequent calls to [`MAX30205::get_temperature`] will return the same value.
-    ///
-    /// You may use [`MAX30205::power_up`] to exit power saving mode and resume continuous updates,
-    /// or [`MAX30205::update_once`] to get on-demand temperature updates while staying in power saving mode.
-    pub fn power_down<E>(&mut self) -> Result<(), E> where I2C: i2c::WriteRead<u8, Error = E> + i2c::Write<u8, Error = E> {
-        self.transform_config(|x| x | 0x01)
+/// A numeric representation that temperature readings can be decoded into.
+///
+/// This is implemented for [`Temperature`], [`f64`], [`f32`], (with the `fixed` feature) `fixed::types::I16F16`,
+/// (with the `uom` feature) `uom::si::f64::ThermodynamicTemperature`, and (with the `measurements` feature) `measurements::Temperature`,
+/// and can be implemented for custom types to be used with [`MAX30205::get_temperature_as`].
+pub trait FromRaw {
+    /// Decodes a raw temperature or threshold register value (in Celsius) encoded with the given data format.
+    /// Each LSB of `raw` is 1/256 °C, relative to the offset defined by `format`.
+    fn from_raw(raw: i16, format: DataFormat) -> Self;
+}
+impl FromRaw for f64 {
+    fn from_raw(raw: i16, format: DataFormat) -> Self {
+        codec::decode_celsius(raw, format)
     }
-    /// Exits power saving mode and resumes continuous temperature updates. See [`MAX30205::power_down`] for details.
-    pub fn power_up<E>(&mut self) -> Result<(), E> where I2C: i2c::WriteRead<u8, Error = E> + i2c::Write<u8, Error = E> {
-        self.transform_config(|x| x & !0x01)
+}
+impl FromRaw for f32 {
+    fn from_raw(raw: i16, format: DataFormat) -> Self {
+        (raw as i32 + format.offset()) as f32 * codec::CELSIUS_PER_LSB as f32
     }
-    /// Performs a single temperature update while in power saving mode.
-    /// When not in power saving mode, this has no effect.
-    /// See [`MAX30205::power_down`] for more details.
-    pub fn update_once<E>(&mut self) -> Result<(), E> where I2C: i2c::WriteRead<u8, Error = E> + i2c::Write<u8, Error = E> {
-        self.transform_config(|x| x | 0x80)
+}
+#[cfg(feature = "fixed")]
+impl FromRaw for fixed::types::I16F16 {
+    fn from_raw(raw: i16, format: DataFormat) -> Self {
+        // one LSB is 2^-8 °C, so just widen the fractional part to 16 bits
+        fixed::types::I16F16::from_bits((raw as i32 + format.offset()) << 8)
     }
+}
+
+driver! {
+    /// A MAX30205 sensor wrapper.
+    MAX30205, Core, embedded_hal::i2c::I2c, embedded_hal::delay::DelayNs, [], []
+}
 
-    /// Gets an instantaneous temperature reading (in Celsius) from the device.
-    pub fn get_temperature<E>(&mut self) -> Result<f64, E> where I2C: i2c::WriteRead<u8, Error = E> {
-        let mut res = [0; 2];
-        self.i2c.write_read(self.addr, &[Register::Temp as u8], &mut res)?;
-        let res = ((res[0] as u16) << 8) | (res[1] as u16);
-        Ok(res as i16 as f64 * 0.00390625)
+#[cfg(feature = "async")]
+mod asynch {
+    driver! {
+        /// An async MAX30205 sensor wrapper.
+        ///
+        /// This has the same API as [`MAX30205`](crate::MAX30205), but communicates over an async I2C bus.
+        /// This includes device discovery, so [`MAX30205Async::scan`] and [`MAX30205Async::scan_all`]
+        /// can be used directly from an async init task.
+        /// Waits are likewise asynchronous; e.g., [`MAX30205Async::read_one_shot`] awaits the conversion time
+        /// via [`embedded_hal_async::delay::DelayNs`], so the executor is free to sleep in the meantime:
+        ///
+        /// ```ignore
+        /// sensor.power_down().await?;
+        /// loop {
+        ///     let temp = sensor.read_one_shot(&mut delay).await?; // the device is only awake during the conversion
+        ///     Timer::after_secs(60).await;
+        /// }
+        /// ```
+        MAX30205Async, CoreAsync, embedded_hal_async::i2c::I2c, embedded_hal_async::delay::DelayNs, [async], [.await]
     }
 }
+#[cfg(feature = "async")]
+pub use asynch::MAX30205Async;
@@ -0,0 +1,190 @@
+//! A generic driver core for the LM75 register map, which the MAX30205 shares with many other temperature sensors.
+//!
+//! Every LM75-family part has the same four registers (see [`Register`]) and encodes temperatures and thresholds
+//! as left-justified two's complement values, so one LSB of the 16-bit register is always 1/256 °C,
+//! with only the number of meaningful bits (the resolution) varying between parts.
+//! [`Core`] owns the bus and performs the register access for any such part, described by a [`Part`],
+//! and `CoreAsync` (with the `async` feature) does the same over an async bus.
+//!
+//! The [`MAX30205`](crate::MAX30205) driver (and its async counterpart) is built on these cores,
+//! adding diagnostics, automatic recovery, calibration, and the MAX30205-specific configuration bits,
+//! so it should be preferred for the MAX30205 itself. Other parts can be used through the cores directly.
+
+use core::marker::PhantomData;
+
+use crate::{Access, AddressSource, Error, Register, Temperature, codec};
+
+/// A description of an LM75-family part.
+pub trait Part {
+    /// Every 7-bit I2C address that the part can be strapped to, in the order they should be scanned.
+    const ADDRESSES: &'static [u8];
+    /// The number of meaningful (most significant) bits of the temperature register.
+    /// This sets the scale of one count of the part, which is 2^(16 - `RESOLUTION_BITS`) / 256 °C, e.g., 0.5 °C for 9 bits.
+    const RESOLUTION_BITS: u32;
+}
+
+/// The MAX30205, with 16 bits (1/256 °C) of resolution.
+#[derive(Debug, Clone, Copy)]
+pub struct MAX30205;
+impl Part for MAX30205 {
+    const ADDRESSES: &'static [u8] = &crate::ADDRESSES;
+    const RESOLUTION_BITS: u32 = 16;
+}
+
+/// The original LM75, with 9 bits (0.5 °C) of resolution.
+#[derive(Debug, Clone, Copy)]
+pub struct LM75;
+impl Part for LM75 {
+    const ADDRESSES: &'static [u8] = &[0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f];
+    const RESOLUTION_BITS: u32 = 9;
+}
+
+/// The TMP1075, with 12 bits (0.0625 °C) of resolution.
+#[derive(Debug, Clone, Copy)]
+pub struct TMP1075;
+impl Part for TMP1075 {
+    const ADDRESSES: &'static [u8] = &[0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f];
+    const RESOLUTION_BITS: u32 = 12;
+}
+
+// Like the driver, the core is written once and instantiated for both the blocking and async embedded-hal traits.
+macro_rules! register_core {
+    ($(#[$meta:meta])* $name:ident, $i2c:path, [$($async:tt)*], [$($await:tt)*]) => {
+        $(#[$meta])*
+        pub struct $name<I2C, P, A = u8> {
+            i2c: I2C,
+            addr: A,
+            _part: PhantomData<P>,
+        }
+        impl<I2C, P: Part> $name<I2C, P> {
+            /// Constructs a driver core for a device at the given address, without touching the bus.
+            pub fn new(addr: u8, i2c: I2C) -> Self {
+                Self::with_address(addr, i2c)
+            }
+        }
+        impl<I2C, P: Part, A: AddressSource> $name<I2C, P, A> {
+            /// Constructs a driver core for a device at the given address, which may be fixed at compile time
+            /// (see [`ConstAddress`](crate::ConstAddress)), without touching the bus.
+            pub fn with_address(addr: A, i2c: I2C) -> Self {
+                Self { i2c, addr, _part: PhantomData }
+            }
+            /// Gets the I2C address of the device.
+            pub fn address(&self) -> u8 {
+                self.addr.get()
+            }
+            /// Gets the stored address.
+            pub(crate) fn address_source(&self) -> A {
+                self.addr
+            }
+            /// Gets the bus back.
+            pub fn into_inner(self) -> I2C {
+                self.i2c
+            }
+        }
+        impl<I2C: $i2c, P: Part> $name<I2C, P> {
+            // checks for an acknowledge with an empty write, treating only a NACK as an absent device
+            pub(crate) $($async)* fn acks(i2c: &mut I2C, addr: u8) -> Result<bool, Error<I2C::Error>> {
+                match i2c.write(addr, &[]) $($await)* {
+                    Ok(()) => Ok(true),
+                    Err(e) => match embedded_hal::i2c::Error::kind(&e) {
+                        embedded_hal::i2c::ErrorKind::NoAcknowledge(_) => Ok(false),
+                        _ => Err(Error::Bus(e)),
+                    },
+                }
+            }
+            /// Scans each of the addresses of the part (in order), returning the first responding address.
+            /// See [`MAX30205::scan`](crate::MAX30205::scan) for details.
+            pub $($async)* fn scan(i2c: &mut I2C) -> Result<u8, Error<I2C::Error>> {
+                for addr in P::ADDRESSES.iter().copied() {
+                    if Self::acks(i2c, addr) $($await)* ? { return Ok(addr) }
+                }
+                Err(Error::NoDevice)
+            }
+        }
+        impl<I2C: $i2c, P: Part, A: AddressSource> $name<I2C, P, A> {
+            /// Checks whether the device still acknowledges its address.
+            /// See [`MAX30205::is_present`](crate::MAX30205::is_present) for details.
+            pub $($async)* fn is_present(&mut self) -> Result<bool, Error<I2C::Error>> {
+                $name::<I2C, P>::acks(&mut self.i2c, self.addr.get()) $($await)*
+            }
+
+            // the bus transfers behind read_register and write_register, which the full driver wraps with its own error handling
+            pub(crate) $($async)* fn read_raw(&mut self, register: Register, buf: &mut [u8]) -> Result<(), I2C::Error> {
+                self.i2c.write_read(self.addr.get(), &[register as u8], buf) $($await)*
+            }
+            pub(crate) $($async)* fn write_raw(&mut self, register: Register, value: &[u8]) -> Result<(), I2C::Error> {
+                let mut data = [register as u8, 0, 0];
+                data[1..=value.len()].copy_from_slice(value); // registers are at most two bytes
+                self.i2c.write(self.addr.get(), &data[..=value.len()]) $($await)*
+            }
+
+            /// Reads a register, filling `buf` with its (big-endian) contents.
+            pub $($async)* fn read_register(&mut self, register: Register, buf: &mut [u8]) -> Result<(), Error<I2C::Error>> {
+                self.read_raw(register, buf) $($await)* .map_err(|error| Error::Register { register, access: Access::Read, error })
+            }
+            /// Writes a register with the given (big-endian) contents.
+            /// Returns [`Error::OutOfRange`] if `value` is longer than any register (two bytes).
+            pub $($async)* fn write_register(&mut self, register: Register, value: &[u8]) -> Result<(), Error<I2C::Error>> {
+                if value.len() > 2 { return Err(Error::OutOfRange) }
+                self.write_raw(register, value) $($await)* .map_err(|error| Error::Register { register, access: Access::Write, error })
+            }
+
+            /// Reads the raw configuration register. The meaning of the bits beyond the common LM75 set varies between parts.
+            pub $($async)* fn config(&mut self) -> Result<u8, Error<I2C::Error>> {
+                let mut buf = [0];
+                self.read_register(Register::Config, &mut buf) $($await)* ?;
+                Ok(buf[0])
+            }
+            /// Writes the raw configuration register.
+            pub $($async)* fn set_config(&mut self, bits: u8) -> Result<(), Error<I2C::Error>> {
+                self.write_register(Register::Config, &[bits]) $($await)*
+            }
+
+            $($async)* fn read16(&mut self, register: Register) -> Result<Temperature, Error<I2C::Error>> {
+                let mut buf = [0; 2];
+                self.read_register(register, &mut buf) $($await)* ?;
+                let mask = !0u16 << (16 - P::RESOLUTION_BITS);
+                Ok(codec::decode((u16::from_be_bytes(buf) & mask) as i16, crate::DataFormat::Normal))
+            }
+            $($async)* fn write16(&mut self, register: Register, value: Temperature) -> Result<(), Error<I2C::Error>> {
+                let raw = codec::encode(value, crate::DataFormat::Normal).ok_or(Error::OutOfRange)?;
+                self.write_register(register, &raw.to_be_bytes()) $($await)*
+            }
+
+            /// Gets the current temperature, truncated to the resolution of the part.
+            pub $($async)* fn temperature(&mut self) -> Result<Temperature, Error<I2C::Error>> {
+                self.read16(Register::Temp) $($await)*
+            }
+            /// Gets the overtemperature shutdown threshold.
+            pub $($async)* fn tos(&mut self) -> Result<Temperature, Error<I2C::Error>> {
+                self.read16(Register::Tos) $($await)*
+            }
+            /// Sets the overtemperature shutdown threshold. The part ignores bits beyond its resolution.
+            /// Returns [`Error::OutOfRange`] if the value cannot be represented.
+            pub $($async)* fn set_tos(&mut self, value: Temperature) -> Result<(), Error<I2C::Error>> {
+                self.write16(Register::Tos, value) $($await)*
+            }
+            /// Gets the hysteresis threshold.
+            pub $($async)* fn thyst(&mut self) -> Result<Temperature, Error<I2C::Error>> {
+                self.read16(Register::Thyst) $($await)*
+            }
+            /// Sets the hysteresis threshold. See [`Self::set_tos`] for details.
+            pub $($async)* fn set_thyst(&mut self, value: Temperature) -> Result<(), Error<I2C::Error>> {
+                self.write16(Register::Thyst, value) $($await)*
+            }
+        }
+    };
+}
+
+register_core! {
+    /// Register access for an LM75-family part. See the [module documentation](self) for details.
+    ///
+    /// Temperatures are in the normal (LM75-compatible) data format.
+    Core, embedded_hal::i2c::I2c, [], []
+}
+#[cfg(feature = "async")]
+register_core! {
+    /// Register access for an LM75-family part over an async bus.
+    /// This has the same API as [`Core`], but communicates over an async I2C bus.
+    CoreAsync, embedded_hal_async::i2c::I2c, [async], [.await]
+}
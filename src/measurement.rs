@@ -0,0 +1,68 @@
+use crate::{DataFormat, FromRaw, Temperature};
+
+const EXTENDED: u8 = 1 << 0;
+const STALE:    u8 = 1 << 1;
+
+/// A compact temperature reading for transmission over low-bandwidth links, as returned by [`MAX30205::get_measurement`](crate::MAX30205::get_measurement).
+///
+/// [`Measurement::to_bytes`] encodes this as 5 bytes: the big-endian raw reading, the status flags, and the big-endian sequence number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Measurement {
+    /// The temperature as a register value in the data format given by the status flags,
+    /// with the driver's calibration and self-heating corrections applied (see [`MAX30205::get_measurement`](crate::MAX30205::get_measurement)).
+    pub raw: i16,
+    /// The status flags. See [`Measurement::data_format`] and [`Measurement::is_stale`].
+    pub status: u8,
+    /// A caller-supplied sequence number, e.g., for detecting lost or reordered readings.
+    pub sequence: u16,
+}
+impl Measurement {
+    /// Constructs a measurement from a raw reading in the given data format.
+    pub fn new(raw: i16, format: DataFormat, stale: bool, sequence: u16) -> Self {
+        let mut status = 0;
+        if format == DataFormat::Extended { status |= EXTENDED }
+        if stale { status |= STALE }
+        Self { raw, status, sequence }
+    }
+    /// Gets the data format of the raw reading.
+    pub fn data_format(&self) -> DataFormat {
+        if self.status & EXTENDED != 0 { DataFormat::Extended } else { DataFormat::Normal }
+    }
+    /// Checks whether the reading was flagged as stale. See [`MAX30205::is_stale`](crate::MAX30205::is_stale).
+    pub fn is_stale(&self) -> bool {
+        self.status & STALE != 0
+    }
+    /// Decodes the reading.
+    pub fn temperature(&self) -> Temperature {
+        <Temperature as FromRaw>::from_raw(self.raw, self.data_format())
+    }
+    /// Serializes this measurement as JSON into `buf`, returning the number of bytes written, e.g., for posting to a REST endpoint.
+    /// The output has the form `{"millicelsius":36809,"sequence":7,"stale":false}`, which is at most 55 bytes long.
+    /// Returns [`BufferFull`](serde_json_core::ser::Error::BufferFull) if `buf` is too small.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self, buf: &mut [u8]) -> Result<usize, serde_json_core::ser::Error> {
+        #[derive(serde::Serialize)]
+        struct Json {
+            millicelsius: i32,
+            sequence: u16,
+            stale: bool,
+        }
+        serde_json_core::to_slice(&Json { millicelsius: self.temperature().as_millicelsius(), sequence: self.sequence, stale: self.is_stale() }, buf)
+    }
+    /// Encodes this measurement as bytes.
+    pub fn to_bytes(&self) -> [u8; 5] {
+        let [raw_hi, raw_lo] = self.raw.to_be_bytes();
+        let [seq_hi, seq_lo] = self.sequence.to_be_bytes();
+        [raw_hi, raw_lo, self.status, seq_hi, seq_lo]
+    }
+    /// Decodes a measurement previously encoded with [`Measurement::to_bytes`].
+    pub fn from_bytes(bytes: [u8; 5]) -> Self {
+        Self {
+            raw: i16::from_be_bytes([bytes[0], bytes[1]]),
+            status: bytes[2],
+            sequence: u16::from_be_bytes([bytes[3], bytes[4]]),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use embedded_hal::i2c::{I2c, ErrorKind};
+
+use crate::{MAX30205, AddressSource, Temperature, Error};
+
+/// An object-safe view of a blocking MAX30205 driver, with the bus type and its error type erased.
+///
+/// This allows drivers on different I2C buses (and therefore of different types) to be handled uniformly,
+/// as is done by [`MultiBusManager`]. Bus errors are reduced to their [`ErrorKind`].
+pub trait AnyMAX30205 {
+    /// Gets the I2C address of the device. See [`MAX30205::address`].
+    fn address(&self) -> u8;
+    /// Gets an instantaneous temperature reading. See [`MAX30205::get_temperature`].
+    fn get_temperature(&mut self) -> Result<Temperature, Error<ErrorKind>>;
+}
+impl<I2C: I2c, A: AddressSource> AnyMAX30205 for MAX30205<I2C, A> {
+    fn address(&self) -> u8 {
+        MAX30205::address(self)
+    }
+    fn get_temperature(&mut self) -> Result<Temperature, Error<ErrorKind>> {
+        MAX30205::get_temperature(self).map_err(|e| e.map_bus(|e| embedded_hal::i2c::Error::kind(&e)))
+    }
+}
+
+/// Identifies a sensor managed by a [`MultiBusManager`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SensorId {
+    /// The caller-assigned index of the I2C bus the sensor is attached to.
+    pub bus: u8,
+    /// The I2C address of the sensor on its bus.
+    pub address: u8,
+}
+
+/// A temperature reading from one sensor of a [`MultiBusManager`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SensorReading {
+    /// The sensor that was read.
+    pub id: SensorId,
+    /// The temperature, or the error that occurred while reading it.
+    pub result: Result<Temperature, Error<ErrorKind>>,
+}
+
+/// A manager for MAX30205 sensors spread across multiple I2C buses.
+///
+/// Each sensor is a separately-constructed driver (on its own bus handle), borrowed as an [`AnyMAX30205`],
+/// along with a caller-assigned bus index used to identify it.
+/// Sensors sharing a bus can be given shared bus handles, e.g., from `embedded-hal-bus`.
+pub struct MultiBusManager<'a, const N: usize> {
+    sensors: [(u8, &'a mut dyn AnyMAX30205); N],
+}
+impl<'a, const N: usize> MultiBusManager<'a, N> {
+    /// Constructs a manager from a list of `(bus, sensor)` pairs.
+    pub fn new(sensors: [(u8, &'a mut dyn AnyMAX30205); N]) -> Self {
+        Self { sensors }
+    }
+    /// Gets the identifiers of all managed sensors, in order.
+    pub fn ids(&self) -> [SensorId; N] {
+        core::array::from_fn(|i| SensorId { bus: self.sensors[i].0, address: self.sensors[i].1.address() })
+    }
+    /// Gets an instantaneous temperature reading from every sensor (in order).
+    /// An error on one sensor does not prevent the others from being read.
+    pub fn read_all(&mut self) -> [SensorReading; N] {
+        core::array::from_fn(|i| {
+            let (bus, sensor) = &mut self.sensors[i];
+            SensorReading { id: SensorId { bus: *bus, address: sensor.address() }, result: sensor.get_temperature() }
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use embedded_hal::i2c::I2c;
+
+use crate::{MAX30205, AddressSource, Temperature, Error};
+
+impl<I2C: I2c, A: AddressSource> MAX30205<I2C, A> {
+    /// Starts a one-shot conversion for polling with [`MAX30205::read_result`], which allows a superloop
+    /// to interleave the sensor with other work instead of blocking for the conversion time.
+    /// See [`MAX30205::update_once`] for details.
+    pub fn start_measurement(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.update_once()
+    }
+    /// Gets the result of the conversion started by [`MAX30205::start_measurement`],
+    /// or [`nb::Error::WouldBlock`] if it is still in progress:
+    ///
+    /// ```ignore
+    /// sensor.start_measurement()?;
+    /// loop {
+    ///     match sensor.read_result() {
+    ///         Ok(temp) => break temp,
+    ///         Err(nb::Error::WouldBlock) => do_other_work(),
+    ///         Err(nb::Error::Other(e)) => return Err(e),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Each poll is a bus transaction, so polling need not be faster than the conversion time (about 50 ms).
+    /// If no conversion was started (or it already completed), this simply returns the current reading.
+    pub fn read_result(&mut self) -> nb::Result<Temperature, Error<I2C::Error>> {
+        if self.is_one_shot_pending()? { return Err(nb::Error::WouldBlock) }
+        Ok(self.get_temperature()?)
+    }
+}
@@ -0,0 +1,34 @@
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::{Error as _, ErrorKind, OutputPin};
+
+/// Half of an SCL clock period, giving the standard-mode rate of 100 kHz.
+const HALF_PERIOD_US: u32 = 5;
+
+/// Performs the standard I2C bus recovery sequence, which frees a bus whose SDA line is held low
+/// by a target that was interrupted mid-transfer (e.g., by a controller reset).
+///
+/// SCL is pulsed 9 times, which lets the target shift out whatever remains of the byte it was sending,
+/// after which a STOP condition is generated to return every target to the idle state.
+///
+/// The pins must be the bus lines, configured as open-drain outputs (so that "high" releases the line),
+/// which typically requires temporarily releasing them from the I2C peripheral.
+/// Afterwards, [`MAX30205::recover_bus`](crate::MAX30205::recover_bus) (or [`MAX30205::is_present`](crate::MAX30205::is_present))
+/// can be used to check that the sensor responds again.
+pub fn recover_bus(scl: &mut impl OutputPin, sda: &mut impl OutputPin, delay: &mut impl DelayNs) -> Result<(), ErrorKind> {
+    sda.set_high().map_err(|e| e.kind())?;
+    for _ in 0..9 {
+        scl.set_low().map_err(|e| e.kind())?;
+        delay.delay_us(HALF_PERIOD_US);
+        scl.set_high().map_err(|e| e.kind())?;
+        delay.delay_us(HALF_PERIOD_US);
+    }
+    // STOP condition: SDA rises while SCL is high
+    scl.set_low().map_err(|e| e.kind())?;
+    sda.set_low().map_err(|e| e.kind())?;
+    delay.delay_us(HALF_PERIOD_US);
+    scl.set_high().map_err(|e| e.kind())?;
+    delay.delay_us(HALF_PERIOD_US);
+    sda.set_high().map_err(|e| e.kind())?;
+    delay.delay_us(HALF_PERIOD_US);
+    Ok(())
+}
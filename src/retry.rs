@@ -0,0 +1,85 @@
+use embedded_hal::i2c::{ErrorType, Operation};
+
+/// A [`DelayNs`](embedded_hal::delay::DelayNs) implementation that does not wait at all.
+/// This is the default for [`RetryI2c`], so that retries are performed immediately.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoDelay;
+impl embedded_hal::delay::DelayNs for NoDelay {
+    fn delay_ns(&mut self, _: u32) {}
+}
+#[cfg(feature = "async")]
+impl embedded_hal_async::delay::DelayNs for NoDelay {
+    async fn delay_ns(&mut self, _: u32) {}
+}
+
+/// An I2C bus wrapper that retries failed transactions, e.g., to ride out occasional NACKs caused by EMI.
+///
+/// Wrapping the bus of a driver applies the retry policy to every register operation of that driver:
+///
+/// ```ignore
+/// let sensor = MAX30205::new(addr, RetryI2c::new(i2c, 3).with_backoff(delay, 1_000))?;
+/// ```
+///
+/// Note that absent devices are indistinguishable from failed transactions,
+/// so scanning through this wrapper retries every unused address.
+pub struct RetryI2c<I2C, D = NoDelay> {
+    i2c: I2C,
+    delay: D,
+    max_attempts: u8,
+    backoff_us: u32,
+    retries: u32,
+}
+impl<I2C> RetryI2c<I2C> {
+    /// Wraps a bus so that each transaction is attempted up to `max_attempts` times (at least once), with no delay between attempts.
+    pub fn new(i2c: I2C, max_attempts: u8) -> Self {
+        Self { i2c, delay: NoDelay, max_attempts: max_attempts.max(1), backoff_us: 0, retries: 0 }
+    }
+}
+impl<I2C, D> RetryI2c<I2C, D> {
+    /// Waits `backoff_us` microseconds (using `delay`) before each retry.
+    pub fn with_backoff<D2>(self, delay: D2, backoff_us: u32) -> RetryI2c<I2C, D2> {
+        RetryI2c { i2c: self.i2c, delay, max_attempts: self.max_attempts, backoff_us, retries: self.retries }
+    }
+    /// Gets the total number of retries performed so far.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+    /// Gets the wrapped bus.
+    pub fn into_inner(self) -> I2C {
+        self.i2c
+    }
+}
+impl<I2C: ErrorType, D> ErrorType for RetryI2c<I2C, D> {
+    type Error = I2C::Error;
+}
+impl<I2C: embedded_hal::i2c::I2c, D: embedded_hal::delay::DelayNs> embedded_hal::i2c::I2c for RetryI2c<I2C, D> {
+    fn transaction(&mut self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+        let mut attempt = 1;
+        loop {
+            match self.i2c.transaction(address, operations) {
+                Err(_) if attempt < self.max_attempts => {
+                    attempt += 1;
+                    self.retries = self.retries.wrapping_add(1);
+                    self.delay.delay_us(self.backoff_us);
+                }
+                res => return res,
+            }
+        }
+    }
+}
+#[cfg(feature = "async")]
+impl<I2C: embedded_hal_async::i2c::I2c, D: embedded_hal_async::delay::DelayNs> embedded_hal_async::i2c::I2c for RetryI2c<I2C, D> {
+    async fn transaction(&mut self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+        let mut attempt = 1;
+        loop {
+            match self.i2c.transaction(address, operations).await {
+                Err(_) if attempt < self.max_attempts => {
+                    attempt += 1;
+                    self.retries = self.retries.wrapping_add(1);
+                    self.delay.delay_us(self.backoff_us).await;
+                }
+                res => return res,
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use embassy_time::{Duration, Ticker};
+use embedded_hal_async::i2c::I2c;
+
+use crate::{MAX30205Async, Clock, Temperature, Error};
+
+/// A periodic temperature sampler for an async sensor, paced by an [`embassy_time::Ticker`].
+///
+/// Each successful reading can be passed through a filter (e.g., a moving average) before being handed to the application.
+/// A typical embassy task looks like:
+///
+/// ```ignore
+/// #[embassy_executor::task]
+/// async fn sample(sensor: MAX30205Async<I2c<'static, Async>>) {
+///     let mut sampler = Sampler::new(sensor, Duration::from_secs(1));
+///     sampler.run(|res| match res {
+///         Ok(temp) => info!("{} °C", temp.as_celsius()),
+///         Err(e) => warn!("{}", e),
+///     }).await
+/// }
+/// ```
+pub struct Sampler<I2C, F = fn(Temperature) -> Temperature> {
+    sensor: MAX30205Async<I2C>,
+    ticker: Ticker,
+    filter: F,
+}
+impl<I2C> Sampler<I2C> {
+    /// Constructs a sampler which reads the sensor every `interval`, with no filtering.
+    /// The first reading is taken one interval after construction.
+    ///
+    /// The interval should be no shorter than the conversion time of the device (about 50 ms),
+    /// or consecutive readings may be identical.
+    pub fn new(sensor: MAX30205Async<I2C>, interval: Duration) -> Self {
+        Self { sensor, ticker: Ticker::every(interval), filter: |x| x }
+    }
+}
+impl<I2C, F: FnMut(Temperature) -> Temperature> Sampler<I2C, F> {
+    /// Sets a filter which every successful reading is passed through. Errors are not filtered.
+    pub fn with_filter<G: FnMut(Temperature) -> Temperature>(self, filter: G) -> Sampler<I2C, G> {
+        Sampler { sensor: self.sensor, ticker: self.ticker, filter }
+    }
+    /// Gets the sampled sensor, e.g., to change its configuration between readings.
+    pub fn sensor(&mut self) -> &mut MAX30205Async<I2C> {
+        &mut self.sensor
+    }
+    /// Gets the sampled sensor back.
+    pub fn into_inner(self) -> MAX30205Async<I2C> {
+        self.sensor
+    }
+}
+impl<I2C: I2c, F: FnMut(Temperature) -> Temperature> Sampler<I2C, F> {
+    /// Waits for the next sampling instant, then reads and filters the temperature.
+    pub async fn next(&mut self) -> Result<Temperature, Error<I2C::Error>> {
+        self.ticker.next().await;
+        Ok((self.filter)(self.sensor.get_temperature().await?))
+    }
+    /// Like [`Sampler::next`], but also returns the time of `clock` at which the reading was taken
+    /// (e.g., [`EmbassyClock`](crate::EmbassyClock), or an RTC for wall-clock timestamps).
+    pub async fn next_timestamped(&mut self, clock: &impl Clock) -> Result<(u32, Temperature), Error<I2C::Error>> {
+        let temp = self.next().await?;
+        Ok((clock.now(), temp))
+    }
+    /// Samples forever, handing every result to `f`.
+    pub async fn run(&mut self, mut f: impl FnMut(Result<Temperature, Error<I2C::Error>>)) -> ! {
+        loop {
+            f(self.next().await)
+        }
+    }
+}
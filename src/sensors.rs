@@ -0,0 +1,33 @@
+use embedded_hal::i2c::I2c;
+use embedded_sensors_hal::sensor::{self, ErrorKind, ErrorType};
+use embedded_sensors_hal::temperature::{DegreesCelsius, TemperatureSensor, TemperatureThresholdSet};
+
+use crate::{MAX30205, AddressSource, Error};
+
+impl<E: core::fmt::Debug> sensor::Error for Error<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::OutOfRange => ErrorKind::InvalidInput,
+            Error::InvalidData | Error::ImplausibleReading => ErrorKind::Other,
+            _ => ErrorKind::Peripheral,
+        }
+    }
+}
+
+impl<I2C: I2c, A: AddressSource> ErrorType for MAX30205<I2C, A> {
+    type Error = Error<I2C::Error>;
+}
+impl<I2C: I2c, A: AddressSource> TemperatureSensor for MAX30205<I2C, A> {
+    fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        self.get_temperature_f32()
+    }
+}
+/// The low threshold is [`MAX30205::set_thyst`] and the high threshold is [`MAX30205::set_tos`].
+impl<I2C: I2c, A: AddressSource> TemperatureThresholdSet for MAX30205<I2C, A> {
+    fn set_temperature_threshold_low(&mut self, threshold: DegreesCelsius) -> Result<(), Self::Error> {
+        self.set_thyst(threshold as f64)
+    }
+    fn set_temperature_threshold_high(&mut self, threshold: DegreesCelsius) -> Result<(), Self::Error> {
+        self.set_tos(threshold as f64)
+    }
+}
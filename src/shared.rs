@@ -0,0 +1,75 @@
+use core::cell::RefCell;
+
+use critical_section::Mutex;
+use embedded_hal::i2c::I2c;
+
+use crate::{MAX30205, Config, Temperature, Error};
+
+/// An interrupt-safe handle to a blocking sensor, which can be placed in a `static` and used from
+/// both thread mode and interrupt handlers (e.g., RTIC `idle` and hardware tasks).
+///
+/// Every access runs inside a critical section, so interrupts are masked for the duration of the bus transactions.
+///
+/// ```ignore
+/// static SENSOR: SharedMAX30205<I2c0> = SharedMAX30205::new();
+///
+/// SENSOR.install(MAX30205::new(addr, i2c)?); // during init
+/// let temp = SENSOR.get_temperature()?;      // from any context
+/// ```
+pub struct SharedMAX30205<I2C> {
+    inner: Mutex<RefCell<Option<MAX30205<I2C>>>>,
+}
+impl<I2C> SharedMAX30205<I2C> {
+    /// Constructs an empty handle. A sensor must be provided by [`SharedMAX30205::install`] before use.
+    pub const fn new() -> Self {
+        Self { inner: Mutex::new(RefCell::new(None)) }
+    }
+    /// Provides the sensor to use, returning the previously installed sensor (if any).
+    pub fn install(&self, sensor: MAX30205<I2C>) -> Option<MAX30205<I2C>> {
+        critical_section::with(|cs| self.inner.borrow_ref_mut(cs).replace(sensor))
+    }
+    /// Removes the installed sensor (if any).
+    pub fn take(&self) -> Option<MAX30205<I2C>> {
+        critical_section::with(|cs| self.inner.borrow_ref_mut(cs).take())
+    }
+    /// Runs `f` with exclusive access to the sensor inside a critical section, e.g., to perform several operations atomically.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no sensor is installed, or if called reentrantly from within `f`.
+    pub fn with<R>(&self, f: impl FnOnce(&mut MAX30205<I2C>) -> R) -> R {
+        critical_section::with(|cs| f(self.inner.borrow_ref_mut(cs).as_mut().expect("no sensor installed in SharedMAX30205")))
+    }
+}
+impl<I2C> Default for SharedMAX30205<I2C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<I2C: I2c> SharedMAX30205<I2C> {
+    /// Gets an instantaneous temperature reading. See [`MAX30205::get_temperature`] for details.
+    pub fn get_temperature(&self) -> Result<Temperature, Error<I2C::Error>> {
+        self.with(|s| s.get_temperature())
+    }
+    /// Gets an instantaneous temperature reading (in thousandths of a degree Celsius).
+    /// See [`MAX30205::get_temperature_millicelsius`] for details.
+    pub fn get_temperature_millicelsius(&self) -> Result<i32, Error<I2C::Error>> {
+        self.with(|s| s.get_temperature_millicelsius())
+    }
+    /// Reads the configuration register. See [`MAX30205::get_config`] for details.
+    pub fn get_config(&self) -> Result<Config, Error<I2C::Error>> {
+        self.with(|s| s.get_config())
+    }
+    /// Writes the configuration register. See [`MAX30205::set_config`] for details.
+    pub fn set_config(&self, config: Config) -> Result<(), Error<I2C::Error>> {
+        self.with(|s| s.set_config(config))
+    }
+    /// Sets the overtemperature shutdown threshold (in Celsius). See [`MAX30205::set_tos`] for details.
+    pub fn set_tos(&self, celsius: f64) -> Result<(), Error<I2C::Error>> {
+        self.with(|s| s.set_tos(celsius))
+    }
+    /// Sets the hysteresis threshold (in Celsius). See [`MAX30205::set_thyst`] for details.
+    pub fn set_thyst(&self, celsius: f64) -> Result<(), Error<I2C::Error>> {
+        self.with(|s| s.set_thyst(celsius))
+    }
+}
@@ -0,0 +1,64 @@
+use core::cell::RefCell;
+
+use embedded_hal::i2c::I2c;
+use embedded_hal_bus::i2c::{RefCellDevice, CriticalSectionDevice};
+#[cfg(target_has_atomic = "8")]
+use embedded_hal_bus::{i2c::AtomicDevice, util::AtomicCell};
+
+use crate::{MAX30205, Error};
+
+/// A shared I2C bus from which per-device handles can be created.
+///
+/// This is implemented for the bus containers of `embedded-hal-bus`: [`RefCell`] (yielding [`RefCellDevice`]),
+/// [`critical_section::Mutex`] (yielding [`CriticalSectionDevice`]), and, on targets with atomics,
+/// [`AtomicCell`](embedded_hal_bus::util::AtomicCell) (yielding [`AtomicDevice`](embedded_hal_bus::i2c::AtomicDevice)).
+pub trait SharedBus {
+    /// The device handle type.
+    type Device<'a>: I2c where Self: 'a;
+    /// Creates a new device handle on this bus.
+    fn device(&self) -> Self::Device<'_>;
+}
+impl<T: I2c> SharedBus for RefCell<T> {
+    type Device<'a> = RefCellDevice<'a, T> where T: 'a;
+    fn device(&self) -> Self::Device<'_> {
+        RefCellDevice::new(self)
+    }
+}
+impl<T: I2c> SharedBus for critical_section::Mutex<RefCell<T>> {
+    type Device<'a> = CriticalSectionDevice<'a, T> where T: 'a;
+    fn device(&self) -> Self::Device<'_> {
+        CriticalSectionDevice::new(self)
+    }
+}
+#[cfg(target_has_atomic = "8")]
+impl<T: I2c> SharedBus for AtomicCell<T> {
+    type Device<'a> = AtomicDevice<'a, T> where T: 'a;
+    fn device(&self) -> Self::Device<'_> {
+        AtomicDevice::new(self)
+    }
+}
+
+impl<'a, D: I2c> MAX30205<D> {
+    /// Constructs a MAX30205 sensor wrapper on a shared bus. See [`MAX30205::new`] for details.
+    ///
+    /// ```ignore
+    /// let bus = RefCell::new(i2c);
+    /// let mut sensor = MAX30205::new_shared(addr, &bus)?;
+    /// let mut other = OtherDevice::new(RefCellDevice::new(&bus));
+    /// ```
+    pub fn new_shared<S: SharedBus<Device<'a> = D> + 'a>(addr: u8, bus: &'a S) -> Result<Self, Error<D::Error>> {
+        Self::new(addr, bus.device())
+    }
+    /// Constructs a MAX30205 sensor wrapper for an already-configured device on a shared bus. See [`MAX30205::attach`] for details.
+    pub fn attach_shared<S: SharedBus<Device<'a> = D> + 'a>(addr: u8, bus: &'a S) -> Self {
+        Self::attach(addr, bus.device())
+    }
+    /// Scans a shared bus for available devices. See [`MAX30205::scan`] for details.
+    ///
+    /// The bus is only locked for the duration of each individual probe, so other users of the bus are not starved by a long scan.
+    /// Addresses occupied by other known peripherals on the bus should be omitted from `addresses`,
+    /// as they would otherwise be mistaken for a sensor.
+    pub fn scan_shared<S: SharedBus<Device<'a> = D> + 'a>(bus: &'a S, addresses: &[u8]) -> Result<u8, Error<D::Error>> {
+        MAX30205::scan(&mut bus.device(), addresses)
+    }
+}
@@ -0,0 +1,145 @@
+use embedded_hal::i2c::{ErrorKind, ErrorType, NoAcknowledgeSource, Operation};
+
+use crate::{MAX30205, Config, Register, Temperature, codec};
+
+/// The simulated temperature over time, for use with [`SimulatedBus`].
+///
+/// This is implemented for [`Temperature`] (a constant temperature)
+/// and for closures mapping the conversion number (starting at zero) to a temperature.
+pub trait Profile {
+    /// Gets the true temperature at the given conversion.
+    fn temperature(&mut self, conversion: u32) -> Temperature;
+}
+impl Profile for Temperature {
+    fn temperature(&mut self, _: u32) -> Temperature {
+        *self
+    }
+}
+impl<F: FnMut(u32) -> Temperature> Profile for F {
+    fn temperature(&mut self, conversion: u32) -> Temperature {
+        self(conversion)
+    }
+}
+
+/// A simulated I2C bus with a single MAX30205 attached, for running application logic and tests on a host with no hardware.
+///
+/// The simulated device implements the register map of the real part, so every driver in this crate works with it unchanged
+/// (see [`SimulatedMAX30205`]). Conversions are performed on demand rather than in real time:
+/// each read of the temperature register while converting continuously performs one conversion,
+/// as does each one-shot request while shut down.
+/// The converted value is taken from the [`Profile`], plus uniform noise (if enabled), and is saturated to the data format.
+///
+/// Transactions to any other address are not acknowledged.
+pub struct SimulatedBus<P> {
+    addr: u8,
+    profile: P,
+    noise: u16,
+    rng: u32,
+    conversions: u32,
+    pointer: u8,
+    config: u8,
+    temp: i16,
+    thyst: i16,
+    tos: i16,
+}
+/// A driver for a simulated MAX30205. See [`SimulatedBus`] for details.
+///
+/// ```ignore
+/// let sensor = SimulatedMAX30205::new(0x48, SimulatedBus::new(0x48, Temperature::from_celsius(36.6)).with_noise(8, 1))?;
+/// ```
+pub type SimulatedMAX30205<P> = MAX30205<SimulatedBus<P>>;
+
+impl<P: Profile> SimulatedBus<P> {
+    /// Constructs a bus with a simulated device at the given address, in its power-on state.
+    pub fn new(addr: u8, profile: P) -> Self {
+        Self {
+            addr, profile, noise: 0, rng: 0, conversions: 0,
+            pointer: Register::Temp as u8, config: 0, temp: 0, thyst: crate::DEFAULT_THYST_RAW, tos: crate::DEFAULT_TOS_RAW,
+        }
+    }
+    /// Adds uniform noise of up to `amplitude` LSBs (1/256 °C each) in either direction to every conversion,
+    /// drawn from a deterministic pseudo-random sequence determined by `seed`.
+    pub fn with_noise(self, amplitude: u16, seed: u32) -> Self {
+        Self { noise: amplitude, rng: seed | 1, ..self }
+    }
+    /// Gets the number of conversions performed so far.
+    pub fn conversions(&self) -> u32 {
+        self.conversions
+    }
+
+    fn noise(&mut self) -> i32 {
+        if self.noise == 0 { return 0 }
+        // xorshift32
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 17;
+        self.rng ^= self.rng << 5;
+        (self.rng % (2 * self.noise as u32 + 1)) as i32 - self.noise as i32
+    }
+    fn convert(&mut self) {
+        let format = Config::from_bits(self.config).data_format;
+        let temp = self.profile.temperature(self.conversions).as_raw() + self.noise();
+        self.conversions = self.conversions.wrapping_add(1);
+        self.temp = match codec::encode(Temperature::from_raw(temp), format) {
+            Some(raw) => raw,
+            None if temp < 0 => i16::MIN,
+            None => i16::MAX,
+        };
+    }
+    fn write(&mut self, bytes: &[u8]) {
+        let Some((&pointer, data)) = bytes.split_first() else { return };
+        self.pointer = pointer;
+        let value16 = match data {
+            [hi, lo, ..] => Some(i16::from_be_bytes([*hi, *lo])),
+            _ => None,
+        };
+        match (pointer, data.first()) {
+            (1, Some(&bits)) => {
+                // the one-shot bit reads back as zero once the conversion (if any) is complete
+                let config = Config::from_bits(bits);
+                self.config = Config { one_shot: false, ..config }.bits();
+                if config.one_shot && config.shutdown { self.convert() }
+            }
+            (2, _) => if let Some(v) = value16 { self.thyst = v },
+            (3, _) => if let Some(v) = value16 { self.tos = v },
+            _ => (), // the temperature register is read-only
+        }
+    }
+    fn read(&mut self, buf: &mut [u8]) {
+        let bytes = match self.pointer & 3 {
+            0 => {
+                if !Config::from_bits(self.config).shutdown { self.convert() }
+                self.temp.to_be_bytes()
+            }
+            1 => [self.config; 2],
+            2 => self.thyst.to_be_bytes(),
+            _ => self.tos.to_be_bytes(),
+        };
+        for (i, b) in buf.iter_mut().enumerate() {
+            *b = bytes[i % 2];
+        }
+    }
+    fn transact(&mut self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), ErrorKind> {
+        if address != self.addr { return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)) }
+        for op in operations {
+            match op {
+                Operation::Write(bytes) => self.write(bytes),
+                Operation::Read(buf) => self.read(buf),
+            }
+        }
+        Ok(())
+    }
+}
+impl<P> ErrorType for SimulatedBus<P> {
+    type Error = ErrorKind;
+}
+impl<P: Profile> embedded_hal::i2c::I2c for SimulatedBus<P> {
+    fn transaction(&mut self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+        self.transact(address, operations)
+    }
+}
+#[cfg(feature = "async")]
+impl<P: Profile> embedded_hal_async::i2c::I2c for SimulatedBus<P> {
+    async fn transaction(&mut self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+        self.transact(address, operations)
+    }
+}
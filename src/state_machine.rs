@@ -0,0 +1,74 @@
+use embedded_hal::i2c::I2c;
+
+use crate::{MAX30205, Temperature, Error, CONVERSION_TIME_MS};
+
+#[derive(Debug, Clone, Copy)]
+enum State {
+    Start,
+    Idle { since: u32 },
+    Converting { since: u32 },
+}
+
+/// A non-blocking measurement sequencer for superloop firmware that can use neither async nor `nb`.
+///
+/// Each measurement powers the device up, waits for a conversion, reads the temperature, and powers the device back down,
+/// so the device stays in power saving mode between measurements.
+/// Every step is performed by [`MAX30205StateMachine::poll`], which never waits, so it can simply be called on every loop iteration:
+///
+/// ```ignore
+/// let mut sampler = MAX30205StateMachine::new(sensor, 1000);
+/// loop {
+///     if let Some(temp) = sampler.poll(millis())? {
+///         display(temp);
+///     }
+///     do_other_work();
+/// }
+/// ```
+pub struct MAX30205StateMachine<I2C> {
+    sensor: MAX30205<I2C>,
+    interval_ms: u32,
+    state: State,
+}
+impl<I2C> MAX30205StateMachine<I2C> {
+    /// Constructs a sequencer that starts a measurement every `interval_ms` milliseconds, beginning with the first poll.
+    pub fn new(sensor: MAX30205<I2C>, interval_ms: u32) -> Self {
+        Self { sensor, interval_ms, state: State::Start }
+    }
+    /// Checks whether a measurement is currently in progress (i.e., the device is powered up).
+    pub fn is_measuring(&self) -> bool {
+        matches!(self.state, State::Converting { .. })
+    }
+    /// Gets the sequenced sensor back.
+    /// If a measurement is in progress, the device is left powered up.
+    pub fn into_inner(self) -> MAX30205<I2C> {
+        self.sensor
+    }
+}
+impl<I2C: I2c> MAX30205StateMachine<I2C> {
+    /// Advances the measurement sequence, returning the temperature when a measurement completes.
+    ///
+    /// `now_ms` is the current time in milliseconds from any monotonic (possibly wrapping) tick source.
+    /// If a step fails, the error is returned and the measurement is abandoned, with the next attempt one interval later.
+    pub fn poll(&mut self, now_ms: u32) -> Result<Option<Temperature>, Error<I2C::Error>> {
+        let res = self.step(now_ms);
+        if res.is_err() { self.state = State::Idle { since: now_ms } }
+        res
+    }
+    fn step(&mut self, now_ms: u32) -> Result<Option<Temperature>, Error<I2C::Error>> {
+        match self.state {
+            State::Idle { since } if now_ms.wrapping_sub(since) < self.interval_ms => Ok(None),
+            State::Start | State::Idle { .. } => {
+                self.sensor.power_up()?;
+                self.state = State::Converting { since: now_ms };
+                Ok(None)
+            }
+            State::Converting { since } if now_ms.wrapping_sub(since) < CONVERSION_TIME_MS => Ok(None),
+            State::Converting { since } => {
+                let temp = self.sensor.get_temperature()?;
+                self.sensor.power_down()?;
+                self.state = State::Idle { since }; // measure the interval from start to start
+                Ok(Some(temp))
+            }
+        }
+    }
+}
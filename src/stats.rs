@@ -0,0 +1,194 @@
+//! Utilities for summarizing sequences of temperature readings.
+//!
+//! Like [`filters`](crate::filters), these operate on readings in thousandths of a degree Celsius
+//! (see [`MAX30205::get_temperature_millicelsius`](crate::MAX30205::get_temperature_millicelsius)) using only integer math.
+
+use crate::codec::div_round;
+
+/// Tracks the lowest and highest readings seen since construction or the last [`MinMaxTracker::reset`],
+/// e.g., to verify that a cold chain or sterilization cycle stayed within limits without logging every reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MinMaxTracker {
+    range: Option<(i32, i32)>,
+}
+impl MinMaxTracker {
+    /// Constructs an empty tracker.
+    pub const fn new() -> Self {
+        Self { range: None }
+    }
+    /// Records a reading.
+    pub fn update(&mut self, millicelsius: i32) {
+        self.range = Some(match self.range {
+            Some((lo, hi)) => (lo.min(millicelsius), hi.max(millicelsius)),
+            None => (millicelsius, millicelsius),
+        });
+    }
+    /// Gets the lowest reading, or `None` if no readings have been recorded.
+    pub fn min(&self) -> Option<i32> {
+        self.range.map(|x| x.0)
+    }
+    /// Gets the highest reading, or `None` if no readings have been recorded.
+    pub fn max(&self) -> Option<i32> {
+        self.range.map(|x| x.1)
+    }
+    /// Discards all recorded readings.
+    pub fn reset(&mut self) {
+        self.range = None;
+    }
+}
+
+/// The mean and spread of a sequence of readings, as returned by [`RunningStats::summary`] and [`WindowStats::summary`].
+/// All values are in thousandths of a degree Celsius (squared for the variance), rounded to the nearest integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Summary {
+    /// The number of readings summarized.
+    pub count: u32,
+    /// The mean of the readings.
+    pub mean: i32,
+    /// The (population) variance of the readings.
+    pub variance: i64,
+    /// The (population) standard deviation of the readings.
+    pub std_dev: i32,
+}
+impl Summary {
+    fn from_sums(count: u32, sum: i128, sum_sq: i128) -> Option<Self> {
+        if count == 0 { return None }
+        let n = count as i128;
+        let var_num = n * sum_sq - sum * sum; // n^2 times the variance, which is exact and nonnegative
+        Some(Self {
+            count,
+            mean: div_round(sum, n) as i32,
+            variance: div_round(var_num, n * n) as i64,
+            // sqrt(var_num) / n, with one extra bit for rounding; this can only exceed i32::MAX by rounding up from it
+            std_dev: div_round((var_num * 4).isqrt(), 2 * n).min(i32::MAX as i128) as i32,
+        })
+    }
+}
+
+/// Accumulates statistics over every reading since construction or the last [`RunningStats::reset`],
+/// e.g., to report measurement noise and stability. This uses constant memory.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunningStats {
+    count: u32,
+    sum: i128,
+    sum_sq: i128,
+}
+impl RunningStats {
+    /// Constructs an empty accumulator.
+    pub const fn new() -> Self {
+        Self { count: 0, sum: 0, sum_sq: 0 }
+    }
+    /// Records a reading. Once `u32::MAX` readings have been recorded, further readings are ignored.
+    pub fn update(&mut self, millicelsius: i32) {
+        if self.count == u32::MAX { return }
+        self.count += 1;
+        self.sum += millicelsius as i128;
+        self.sum_sq += millicelsius as i128 * millicelsius as i128;
+    }
+    /// Summarizes the recorded readings, or returns `None` if there are none.
+    pub fn summary(&self) -> Option<Summary> {
+        Summary::from_sums(self.count, self.sum, self.sum_sq)
+    }
+    /// Discards all recorded readings.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Accumulates statistics over the last `N` readings.
+///
+/// Until `N` readings have been seen, the statistics are over the readings seen so far.
+#[derive(Debug, Clone)]
+pub struct WindowStats<const N: usize> {
+    window: [i32; N],
+    next: usize,
+    len: usize,
+    sum: i128,
+    sum_sq: i128,
+}
+impl<const N: usize> WindowStats<N> {
+    const VALID: () = assert!(N > 0 && N <= u32::MAX as usize, "statistics window must be nonempty and fit in a u32");
+
+    /// Constructs an empty accumulator.
+    pub const fn new() -> Self {
+        let () = Self::VALID;
+        Self { window: [0; N], next: 0, len: 0, sum: 0, sum_sq: 0 }
+    }
+    /// Records a reading, discarding the oldest reading if the window is full.
+    pub fn update(&mut self, millicelsius: i32) {
+        if self.len == N {
+            let old = self.window[self.next] as i128;
+            self.sum -= old;
+            self.sum_sq -= old * old;
+        } else {
+            self.len += 1;
+        }
+        self.window[self.next] = millicelsius;
+        self.sum += millicelsius as i128;
+        self.sum_sq += millicelsius as i128 * millicelsius as i128;
+        self.next = (self.next + 1) % N;
+    }
+    /// Summarizes the readings in the window, or returns `None` if there are none.
+    pub fn summary(&self) -> Option<Summary> {
+        Summary::from_sums(self.len as u32, self.sum, self.sum_sq)
+    }
+    /// Discards all recorded readings.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+impl<const N: usize> Default for WindowStats<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes the rate of change of the temperature (in thousandths of a degree Celsius per minute)
+/// from timestamped readings, e.g., to detect a detached probe (sudden drop) or the end of a warm-up.
+///
+/// The rate is taken between the newest and oldest of the last `N` readings,
+/// so larger windows trade response time for less sensitivity to noise.
+#[derive(Debug, Clone)]
+pub struct RateOfChange<const N: usize> {
+    ticks_per_second: u32,
+    window: [(u32, i32); N],
+    next: usize,
+    len: usize,
+}
+impl<const N: usize> RateOfChange<N> {
+    const VALID: () = assert!(N >= 2, "rate of change window must hold at least two readings");
+
+    /// Constructs an empty rate tracker whose timestamps come from a (possibly wrapping) tick source running at `ticks_per_second`.
+    pub const fn new(ticks_per_second: u32) -> Self {
+        let () = Self::VALID;
+        Self { ticks_per_second, window: [(0, 0); N], next: 0, len: 0 }
+    }
+    /// Records a reading taken at time `ticks`.
+    pub fn update(&mut self, ticks: u32, millicelsius: i32) {
+        self.window[self.next] = (ticks, millicelsius);
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+    }
+    /// Records a reading taken now, according to `clock` (which must run at the configured rate).
+    pub fn update_now(&mut self, clock: &impl crate::Clock, millicelsius: i32) {
+        self.update(clock.now(), millicelsius);
+    }
+    /// Gets the rate of change (in thousandths of a degree Celsius per minute),
+    /// or `None` if fewer than two readings have been recorded or they share a timestamp.
+    pub fn rate(&self) -> Option<i32> {
+        if self.len < 2 { return None }
+        let newest = self.window[(self.next + N - 1) % N];
+        let oldest = self.window[(self.next + N - self.len) % N];
+        let dt = newest.0.wrapping_sub(oldest.0) as i128;
+        if dt == 0 { return None }
+        // as in Summary, this cannot overflow for any readings or tick rate, and the result saturates
+        let dv = (newest.1 as i128 - oldest.1 as i128) * 60 * self.ticks_per_second as i128;
+        Some(div_round(dv, dt).clamp(i32::MIN as i128, i32::MAX as i128) as i32)
+    }
+    /// Discards all recorded readings.
+    pub fn reset(&mut self) {
+        self.len = 0;
+    }
+}
@@ -0,0 +1,21 @@
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::I2c;
+use futures_core::Stream;
+
+use crate::{MAX30205Async, AddressSource, Temperature, Error};
+
+impl<I2C: I2c, A: AddressSource> MAX30205Async<I2C, A> {
+    /// Gets an endless stream of temperature readings, taken every `interval_us` microseconds as measured by `delay`.
+    /// This allows readings to be combined with other event sources using stream combinators or `select!`.
+    ///
+    /// The first reading is taken immediately. Errors are yielded rather than ending the stream,
+    /// so the consumer decides whether to continue.
+    /// The stream must be pinned before polling, e.g., with [`core::pin::pin!`].
+    pub fn readings<'a, D: DelayNs>(&'a mut self, delay: &'a mut D, interval_us: u32) -> impl Stream<Item = Result<Temperature, Error<I2C::Error>>> + 'a {
+        futures_util::stream::unfold((self, delay, true), move |(sensor, delay, first)| async move {
+            if !first { delay.delay_us(interval_us).await }
+            let res = sensor.get_temperature().await;
+            Some((res, (sensor, delay, false)))
+        })
+    }
+}
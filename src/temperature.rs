@@ -0,0 +1,135 @@
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::codec::{self, CELSIUS_PER_LSB};
+use crate::{DataFormat, FromRaw};
+
+/// A temperature, as returned by the reading APIs such as [`MAX30205::get_temperature`](crate::MAX30205::get_temperature).
+///
+/// This is stored exactly at the device resolution of 1/256 °C, so readings can be compared and combined
+/// without rounding, and the unit of the value is explicit at every use.
+/// Arithmetic is also provided for computing differences and offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Temperature(i32);
+impl Temperature {
+    /// Constructs a temperature from a count of 1/256 °C steps above 0 °C.
+    pub const fn from_raw(raw: i32) -> Self {
+        Self(raw)
+    }
+    /// Constructs a temperature (rounded to the nearest 1/256 °C) from a value in Celsius.
+    /// Values beyond the range of the representation saturate.
+    pub fn from_celsius(celsius: f64) -> Self {
+        Self(codec::round_for_cast(celsius / CELSIUS_PER_LSB) as i32) // the cast saturates
+    }
+    /// Constructs a temperature (rounded to the nearest 1/256 °C) from a value in thousandths of a degree Celsius.
+    pub const fn from_millicelsius(millicelsius: i32) -> Self {
+        Self(codec::millicelsius_to_lsb(millicelsius as i64) as i32)
+    }
+    /// Gets the temperature as a count of 1/256 °C steps above 0 °C.
+    /// Unlike the register value (see [`MAX30205::get_temperature_raw`](crate::MAX30205::get_temperature_raw)),
+    /// this does not depend on the [`DataFormat`].
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+    /// Gets the temperature in Celsius.
+    pub fn as_celsius(self) -> f64 {
+        self.0 as f64 * CELSIUS_PER_LSB
+    }
+    /// Gets the temperature in Fahrenheit.
+    pub fn as_fahrenheit(self) -> f64 {
+        self.as_celsius() * 1.8 + 32.0
+    }
+    /// Gets the temperature in Kelvin.
+    pub fn as_kelvin(self) -> f64 {
+        self.as_celsius() + 273.15
+    }
+    /// Gets the temperature (rounded to the nearest integer) in thousandths of a degree Celsius.
+    pub const fn as_millicelsius(self) -> i32 {
+        codec::lsb_to_millicelsius(self.0 as i64) as i32
+    }
+    /// Splits the temperature (in Celsius, rounded to `precision` decimal places) into its sign, integer part, and fractional digits.
+    fn decimal(self, precision: u32) -> (bool, u64, u64) {
+        let scale = 10u64.pow(precision);
+        let scaled = (self.0.unsigned_abs() as u64 * scale + 128) / 256; // round half away from zero
+        (self.0 < 0 && scaled != 0, scaled / scale, scaled % scale)
+    }
+}
+/// Formats the temperature in Celsius using only integer math, e.g., `36.81°C`.
+/// The number of decimal places defaults to 2, and can be set with the usual precision syntax (e.g., `{:.3}`),
+/// up to 8 places, which is exact at the device resolution.
+impl core::fmt::Display for Temperature {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(2).min(8);
+        let (negative, int, frac) = self.decimal(precision as u32);
+        let sign = if negative { "-" } else { "" };
+        if precision == 0 {
+            write!(f, "{sign}{int}°C")
+        } else {
+            write!(f, "{sign}{int}.{frac:0precision$}°C")
+        }
+    }
+}
+/// Formats the temperature in Celsius with 2 decimal places, e.g., `36.81°C`. See the [`Display`](core::fmt::Display) implementation for details.
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for Temperature {
+    fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        let (negative, int, frac) = self.decimal(2);
+        if negative { f.write_str("-")? }
+        ufmt::uwrite!(f, "{}.", int)?;
+        if frac < 10 { f.write_str("0")? } // ufmt has no zero padding
+        ufmt::uwrite!(f, "{}°C", frac)
+    }
+}
+impl FromRaw for Temperature {
+    fn from_raw(raw: i16, format: DataFormat) -> Self {
+        codec::decode(raw, format)
+    }
+}
+impl Add for Temperature {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+impl Sub for Temperature {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+impl AddAssign for Temperature {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+impl SubAssign for Temperature {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+#[cfg(feature = "uom")]
+impl From<Temperature> for uom::si::f64::ThermodynamicTemperature {
+    fn from(t: Temperature) -> Self {
+        uom::si::f64::ThermodynamicTemperature::new::<uom::si::thermodynamic_temperature::degree_celsius>(t.as_celsius())
+    }
+}
+#[cfg(feature = "uom")]
+impl FromRaw for uom::si::f64::ThermodynamicTemperature {
+    fn from_raw(raw: i16, format: DataFormat) -> Self {
+        <Temperature as FromRaw>::from_raw(raw, format).into()
+    }
+}
+#[cfg(feature = "measurements")]
+impl From<Temperature> for measurements::Temperature {
+    fn from(t: Temperature) -> Self {
+        measurements::Temperature::from_celsius(t.as_celsius())
+    }
+}
+#[cfg(feature = "measurements")]
+impl FromRaw for measurements::Temperature {
+    fn from_raw(raw: i16, format: DataFormat) -> Self {
+        <Temperature as FromRaw>::from_raw(raw, format).into()
+    }
+}
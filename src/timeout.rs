@@ -0,0 +1,31 @@
+use core::future::{poll_fn, Future};
+use core::pin::pin;
+use core::task::Poll;
+
+use crate::Error;
+
+/// Bounds the duration of an async driver operation, returning [`Error::Timeout`] if it does not complete
+/// within `timeout_us` microseconds (as measured by `delay`). This protects the caller from I2C HALs that
+/// never complete a transaction on a stuck bus:
+///
+/// ```ignore
+/// let temp = max30205::with_timeout(&mut delay, 10_000, sensor.get_temperature()).await?;
+/// ```
+///
+/// On timeout, the operation is dropped, which may leave the bus in the middle of a transaction.
+/// Bus recovery is left to the caller (or the HAL).
+///
+/// Only the async driver can be bounded this way, as a blocking call cannot be preempted;
+/// with [`MAX30205`](crate::MAX30205), use a HAL that supports bus timeouts instead.
+pub async fn with_timeout<T, E>(delay: &mut impl embedded_hal_async::delay::DelayNs, timeout_us: u32, op: impl Future<Output = Result<T, Error<E>>>) -> Result<T, Error<E>> {
+    let mut op = pin!(op);
+    let mut timer = pin!(delay.delay_us(timeout_us));
+    poll_fn(|cx| {
+        // the operation is polled first, so one that completes at the deadline is not reported as timed out
+        if let Poll::Ready(res) = op.as_mut().poll(cx) { return Poll::Ready(res) }
+        match timer.as_mut().poll(cx) {
+            Poll::Ready(()) => Poll::Ready(Err(Error::Timeout)),
+            Poll::Pending => Poll::Pending,
+        }
+    }).await
+}
@@ -0,0 +1,97 @@
+use core::marker::PhantomData;
+
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::{ErrorType, I2c};
+
+use crate::{MAX30205, Temperature, Error};
+
+/// The power mode of a [`MAX30205Typed`] in which the device converts continuously.
+#[derive(Debug, Clone, Copy)]
+pub struct Continuous;
+/// The power mode of a [`MAX30205Typed`] in which the device is in power saving (shutdown) mode.
+#[derive(Debug, Clone, Copy)]
+pub struct Shutdown;
+
+/// A MAX30205 sensor wrapper that tracks the power mode of the device in its type,
+/// so that only the reading methods which produce fresh values in the current mode are available.
+/// In particular, a shut-down device can only be read via [`MAX30205Typed::read_one_shot`],
+/// rather than silently returning the stale value of [`MAX30205::get_temperature`].
+///
+/// ```ignore
+/// let sensor = MAX30205Typed::new(0x48, i2c)?;         // MAX30205Typed<_, Continuous>
+/// let temp = sensor.get_temperature()?;
+/// let mut sensor = sensor.power_down().map_err(|(_, e)| e)?; // MAX30205Typed<_, Shutdown>
+/// let temp = sensor.read_one_shot(&mut delay)?;
+/// ```
+///
+/// Mode transitions that fail return the wrapper unchanged along with the error,
+/// as the device is then most likely still in its previous mode.
+/// The driver should not be used to change the power mode behind the wrapper's back.
+pub struct MAX30205Typed<I2C, M> {
+    inner: MAX30205<I2C>,
+    _mode: PhantomData<M>,
+}
+
+// the result of a mode transition, which gives back the unchanged wrapper on failure
+type Transition<I2C, From, To> = Result<MAX30205Typed<I2C, To>, (MAX30205Typed<I2C, From>, Error<<I2C as ErrorType>::Error>)>;
+impl<I2C, M> MAX30205Typed<I2C, M> {
+    fn wrap<N>(inner: MAX30205<I2C>) -> MAX30205Typed<I2C, N> {
+        MAX30205Typed { inner, _mode: PhantomData }
+    }
+    /// Gets the I2C address of the device.
+    pub fn address(&self) -> u8 {
+        self.inner.address()
+    }
+    /// Gets the underlying driver, which does not track the power mode.
+    pub fn into_inner(self) -> MAX30205<I2C> {
+        self.inner
+    }
+}
+impl<I2C: I2c> MAX30205Typed<I2C, Continuous> {
+    /// Constructs and initializes a MAX30205 sensor wrapper, which converts continuously. See [`MAX30205::new`] for details.
+    pub fn new(addr: u8, i2c: I2C) -> Result<Self, Error<I2C::Error>> {
+        Ok(Self::wrap(MAX30205::new(addr, i2c)?))
+    }
+    /// Wraps an existing driver, first ensuring that the device converts continuously.
+    pub fn continuous(mut inner: MAX30205<I2C>) -> Result<Self, (MAX30205<I2C>, Error<I2C::Error>)> {
+        match inner.power_up() {
+            Ok(()) => Ok(Self::wrap(inner)),
+            Err(e) => Err((inner, e)),
+        }
+    }
+    /// Gets an instantaneous temperature reading. See [`MAX30205::get_temperature`] for details.
+    pub fn get_temperature(&mut self) -> Result<Temperature, Error<I2C::Error>> {
+        self.inner.get_temperature()
+    }
+    /// Gets an instantaneous temperature reading (in thousandths of a degree Celsius). See [`MAX30205::get_temperature_millicelsius`] for details.
+    pub fn get_temperature_millicelsius(&mut self) -> Result<i32, Error<I2C::Error>> {
+        self.inner.get_temperature_millicelsius()
+    }
+    /// Transitions the device into power saving mode. See [`MAX30205::power_down`] for details.
+    pub fn power_down(mut self) -> Transition<I2C, Continuous, Shutdown> {
+        match self.inner.power_down() {
+            Ok(()) => Ok(Self::wrap(self.inner)),
+            Err(e) => Err((self, e)),
+        }
+    }
+}
+impl<I2C: I2c> MAX30205Typed<I2C, Shutdown> {
+    /// Wraps an existing driver, first putting the device into power saving mode.
+    pub fn shutdown(mut inner: MAX30205<I2C>) -> Result<Self, (MAX30205<I2C>, Error<I2C::Error>)> {
+        match inner.power_down() {
+            Ok(()) => Ok(Self::wrap(inner)),
+            Err(e) => Err((inner, e)),
+        }
+    }
+    /// Performs a one-shot conversion and returns the fresh reading. See [`MAX30205::read_one_shot`] for details.
+    pub fn read_one_shot(&mut self, delay: &mut impl DelayNs) -> Result<Temperature, Error<I2C::Error>> {
+        self.inner.read_one_shot(delay)
+    }
+    /// Exits power saving mode and resumes continuous conversions. See [`MAX30205::power_up`] for details.
+    pub fn power_up(mut self) -> Transition<I2C, Shutdown, Continuous> {
+        match self.inner.power_up() {
+            Ok(()) => Ok(Self::wrap(self.inner)),
+            Err(e) => Err((self, e)),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use core::fmt::Debug;
+use core::ops::{Deref, DerefMut};
+
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::I2c;
+
+use crate::{MAX30205, Config, Temperature, Error};
+
+#[track_caller]
+fn check<T, E: Debug>(addr: u8, what: &str, res: Result<T, Error<E>>) -> T {
+    match res {
+        Ok(x) => x,
+        Err(e) => panic!("MAX30205 at {addr:#04x}: failed to {what}: {e}"),
+    }
+}
+
+/// A MAX30205 sensor wrapper that panics on any error instead of returning it,
+/// intended for quick prototypes and demos where error handling would only add noise.
+///
+/// Each panic message names the device address and the failed operation.
+/// Only the most common operations are provided here; the full fallible [`MAX30205`] API is still available through [`Deref`].
+/// Production code should use [`MAX30205`] directly.
+pub struct MAX30205Unwrapped<I2C> {
+    inner: MAX30205<I2C>,
+}
+impl<I2C> MAX30205Unwrapped<I2C> {
+    /// Wraps an existing driver.
+    pub fn from_inner(inner: MAX30205<I2C>) -> Self {
+        Self { inner }
+    }
+    /// Gets the underlying fallible driver.
+    pub fn into_inner(self) -> MAX30205<I2C> {
+        self.inner
+    }
+}
+impl<I2C: I2c> MAX30205Unwrapped<I2C> {
+    /// Constructs and initializes a MAX30205 sensor wrapper. See [`MAX30205::new`] for details.
+    #[track_caller]
+    pub fn new(addr: u8, i2c: I2C) -> Self {
+        Self { inner: check(addr, "initialize", MAX30205::new(addr, i2c)) }
+    }
+    /// Reads the configuration register. See [`MAX30205::get_config`] for details.
+    #[track_caller]
+    pub fn get_config(&mut self) -> Config {
+        check(self.inner.address(), "read the configuration", self.inner.get_config())
+    }
+    /// Writes the configuration register. See [`MAX30205::set_config`] for details.
+    #[track_caller]
+    pub fn set_config(&mut self, config: Config) {
+        check(self.inner.address(), "write the configuration", self.inner.set_config(config))
+    }
+    /// Sets the overtemperature shutdown threshold (in Celsius). See [`MAX30205::set_tos`] for details.
+    #[track_caller]
+    pub fn set_tos(&mut self, celsius: f64) {
+        check(self.inner.address(), "set the overtemperature threshold", self.inner.set_tos(celsius))
+    }
+    /// Sets the hysteresis threshold (in Celsius). See [`MAX30205::set_thyst`] for details.
+    #[track_caller]
+    pub fn set_thyst(&mut self, celsius: f64) {
+        check(self.inner.address(), "set the hysteresis threshold", self.inner.set_thyst(celsius))
+    }
+    /// Transitions the device into power saving mode. See [`MAX30205::power_down`] for details.
+    #[track_caller]
+    pub fn power_down(&mut self) {
+        check(self.inner.address(), "power down", self.inner.power_down())
+    }
+    /// Transitions the device out of power saving mode. See [`MAX30205::power_up`] for details.
+    #[track_caller]
+    pub fn power_up(&mut self) {
+        check(self.inner.address(), "power up", self.inner.power_up())
+    }
+    /// Performs a one-shot conversion and reads the result. See [`MAX30205::read_one_shot`] for details.
+    #[track_caller]
+    pub fn read_one_shot(&mut self, delay: &mut impl DelayNs) -> Temperature {
+        check(self.inner.address(), "perform a one-shot conversion", self.inner.read_one_shot(delay))
+    }
+    /// Gets an instantaneous temperature reading. See [`MAX30205::get_temperature`] for details.
+    #[track_caller]
+    pub fn get_temperature(&mut self) -> Temperature {
+        check(self.inner.address(), "read the temperature", self.inner.get_temperature())
+    }
+    /// Gets an instantaneous temperature reading (in thousandths of a degree Celsius).
+    /// See [`MAX30205::get_temperature_millicelsius`] for details.
+    #[track_caller]
+    pub fn get_temperature_millicelsius(&mut self) -> i32 {
+        check(self.inner.address(), "read the temperature", self.inner.get_temperature_millicelsius())
+    }
+}
+impl<I2C> Deref for MAX30205Unwrapped<I2C> {
+    type Target = MAX30205<I2C>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+impl<I2C> DerefMut for MAX30205Unwrapped<I2C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
@@ -0,0 +1,71 @@
+use max30205::{ADDRESSES, Address, Pin};
+use max30205::Pin::{Gnd, Scl, Sda, Vdd};
+
+// every valid strap as (A2, A1, A0, address), per Table 1 of the datasheet
+const STRAPS: [(Pin, Pin, Pin, u8); 32] = [
+    (Gnd, Gnd, Gnd, 0x48),
+    (Gnd, Gnd, Vdd, 0x49),
+    (Gnd, Vdd, Gnd, 0x4a),
+    (Gnd, Vdd, Vdd, 0x4b),
+    (Gnd, Scl, Gnd, 0x4c),
+    (Gnd, Scl, Vdd, 0x4d),
+    (Gnd, Sda, Gnd, 0x4e),
+    (Gnd, Sda, Vdd, 0x4f),
+    (Vdd, Gnd, Gnd, 0x40),
+    (Vdd, Gnd, Vdd, 0x41),
+    (Vdd, Vdd, Gnd, 0x42),
+    (Vdd, Vdd, Vdd, 0x43),
+    (Vdd, Scl, Gnd, 0x44),
+    (Vdd, Scl, Vdd, 0x45),
+    (Vdd, Sda, Gnd, 0x46),
+    (Vdd, Sda, Vdd, 0x47),
+    (Scl, Gnd, Gnd, 0x50),
+    (Scl, Gnd, Vdd, 0x51),
+    (Scl, Vdd, Gnd, 0x52),
+    (Scl, Vdd, Vdd, 0x53),
+    (Scl, Scl, Gnd, 0x54),
+    (Scl, Scl, Vdd, 0x55),
+    (Scl, Sda, Gnd, 0x56),
+    (Scl, Sda, Vdd, 0x57),
+    (Sda, Gnd, Gnd, 0x58),
+    (Sda, Gnd, Vdd, 0x59),
+    (Sda, Vdd, Gnd, 0x5a),
+    (Sda, Vdd, Vdd, 0x5b),
+    (Sda, Scl, Gnd, 0x5c),
+    (Sda, Scl, Vdd, 0x5d),
+    (Sda, Sda, Gnd, 0x5e),
+    (Sda, Sda, Vdd, 0x5f),
+];
+
+#[test]
+fn from_pins_matches_datasheet() {
+    for (a2, a1, a0, addr) in STRAPS {
+        assert_eq!(Address::from_pins(a2, a1, a0).map(Address::get), Some(addr), "{a2:?} {a1:?} {a0:?}");
+    }
+}
+
+#[test]
+fn from_pins_covers_addresses() {
+    let mut found = STRAPS.map(|(a2, a1, a0, _)| Address::from_pins(a2, a1, a0).unwrap().get());
+    let mut expected = ADDRESSES;
+    found.sort();
+    expected.sort();
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn a0_only_accepts_supply_pins() {
+    for a2 in [Gnd, Vdd, Scl, Sda] {
+        for a1 in [Gnd, Vdd, Scl, Sda] {
+            assert_eq!(Address::from_pins(a2, a1, Scl), None);
+            assert_eq!(Address::from_pins(a2, a1, Sda), None);
+        }
+    }
+}
+
+#[test]
+fn new_accepts_only_addresses() {
+    for addr in 0..=0x7f {
+        assert_eq!(Address::new(addr).is_some(), ADDRESSES.contains(&addr), "{addr:#04x}");
+    }
+}
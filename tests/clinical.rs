@@ -0,0 +1,44 @@
+use max30205::clinical::{Prediction, SteadyStatePredictor};
+use max30205::Temperature;
+
+// a probe warming from 25 °C towards 37 °C with a time constant of about 10 readings
+fn warm_up(k: i32) -> Temperature {
+    Temperature::from_celsius(37.0 - 12.0 * 0.9f64.powi(k))
+}
+
+#[test]
+fn predicts_exponential_warm_up() {
+    let mut predictor = SteadyStatePredictor::<9>::new(Temperature::from_celsius(0.05));
+    for k in 0..8 {
+        assert_eq!(predictor.update(warm_up(k)), None);
+    }
+    let (k, prediction) = (8..100).find_map(|k| predictor.update(warm_up(k)).filter(|p| p.confident).map(|p| (k, p))).unwrap();
+    assert!((prediction.estimate.as_celsius() - 37.0).abs() <= 0.05, "{prediction:?}");
+    // well before the readings themselves are close
+    assert!(37.0 - warm_up(k).as_celsius() > 2.0, "confident only after {k} readings");
+}
+
+#[test]
+fn settled_readings_predict_themselves() {
+    let mut predictor = SteadyStatePredictor::<3>::new(Temperature::from_raw(0));
+    let t = Temperature::from_celsius(36.75);
+    assert_eq!(predictor.update(t), None);
+    assert_eq!(predictor.update(t), None);
+    assert_eq!(predictor.update(t), Some(Prediction { estimate: t, confident: false }));
+    assert_eq!(predictor.update(t), Some(Prediction { estimate: t, confident: false }));
+    assert_eq!(predictor.update(t), Some(Prediction { estimate: t, confident: true }));
+}
+
+#[test]
+fn non_settling_readings_are_not_confident() {
+    let mut predictor = SteadyStatePredictor::<3>::new(Temperature::from_celsius(10.0));
+    // a steady rise has no asymptote, so the newest segment is reported as is
+    for k in 0..10 {
+        let t = Temperature::from_celsius(30.0 + k as f64);
+        if let Some(p) = predictor.update(t) {
+            assert_eq!(p, Prediction { estimate: t, confident: false });
+        }
+    }
+    predictor.reset();
+    assert_eq!(predictor.update(Temperature::from_celsius(37.0)), None);
+}
@@ -0,0 +1,43 @@
+//! Bus transaction fixtures shared by the mock-based test suites, for both embedded-hal-mock flavors.
+#![allow(dead_code)] // each test suite uses a different subset
+
+macro_rules! fixtures {
+    ($transaction:ty, $nack:expr) => {
+        /// The transactions performed by [`MAX30205::new`](max30205::MAX30205::new) for a device at `addr`.
+        pub fn init(addr: u8) -> Vec<$transaction> {
+            vec![
+                <$transaction>::write(addr, vec![1, 0x00]),
+                <$transaction>::write(addr, vec![2, 0x00, 0x00]),
+                <$transaction>::write(addr, vec![3, 0x00, 0x00]),
+            ]
+        }
+        /// A read of the temperature register of the device at `addr`, returning `raw`.
+        pub fn read_temp(addr: u8, raw: [u8; 2]) -> $transaction {
+            <$transaction>::write_read(addr, vec![0], raw.to_vec())
+        }
+        /// A presence check that is acknowledged by a device at `addr`.
+        pub fn ack(addr: u8) -> $transaction {
+            <$transaction>::write(addr, vec![])
+        }
+        /// A presence check that is not acknowledged, as there is no device at `addr`.
+        pub fn nack(addr: u8) -> $transaction {
+            <$transaction>::write(addr, vec![]).with_error($nack)
+        }
+    };
+}
+
+pub mod eh1 {
+    use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+    use embedded_hal_mock::eh1::i2c::Transaction;
+
+    fixtures!(Transaction, ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+}
+
+/// The embedded-hal 0.2 errors are opaque, so a missing acknowledge is an arbitrary I/O error.
+#[cfg(feature = "embedded-hal-02")]
+pub mod eh0 {
+    use embedded_hal_mock::eh0::i2c::Transaction;
+    use embedded_hal_mock::eh0::MockError;
+
+    fixtures!(Transaction, MockError::Io(std::io::ErrorKind::Other));
+}
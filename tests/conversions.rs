@@ -0,0 +1,83 @@
+use max30205::{codec, DataFormat, FromRaw, Measurement, Temperature};
+use proptest::prelude::*;
+
+fn format() -> impl Strategy<Value = DataFormat> {
+    prop_oneof![Just(DataFormat::Normal), Just(DataFormat::Extended)]
+}
+fn offset(format: DataFormat) -> i32 {
+    match format {
+        DataFormat::Normal => 0,
+        DataFormat::Extended => 64 * 256,
+    }
+}
+
+proptest! {
+    #[test]
+    fn raw_round_trips(raw: i16, format in format()) {
+        prop_assert_eq!(codec::encode(codec::decode(raw, format), format), Some(raw));
+        prop_assert_eq!(codec::encode_celsius(codec::decode_celsius(raw, format), format), Some(raw));
+    }
+
+    #[test]
+    fn sign_follows_offset_value(raw: i16, format in format()) {
+        let value = raw as i32 + offset(format);
+        let celsius = codec::decode_celsius(raw, format);
+        let millicelsius = codec::decode_millicelsius(raw, format);
+        prop_assert_eq!(celsius < 0.0, value < 0);
+        prop_assert_eq!(celsius == 0.0, value == 0);
+        prop_assert_eq!(millicelsius < 0, value < 0);
+        prop_assert_eq!(millicelsius.signum(), value.signum());
+    }
+
+    #[test]
+    fn representations_agree(raw: i16, format in format()) {
+        let celsius = f64::from_raw(raw, format);
+        prop_assert_eq!(celsius, (raw as i32 + offset(format)) as f64 / 256.0);
+        prop_assert_eq!(f32::from_raw(raw, format) as f64, celsius); // exact, as 24 bits suffice
+        prop_assert_eq!(<Temperature as FromRaw>::from_raw(raw, format).as_celsius(), celsius);
+        prop_assert!((codec::decode_millicelsius(raw, format) as f64 - celsius * 1000.0).abs() <= 0.5);
+        #[cfg(feature = "fixed")]
+        prop_assert_eq!(fixed::types::I16F16::from_raw(raw, format).to_num::<f64>(), celsius);
+    }
+
+    #[test]
+    fn temperature_round_trips(raw in -(1 << 24)..(1 << 24)) {
+        let temp = Temperature::from_raw(raw);
+        prop_assert_eq!(Temperature::from_celsius(temp.as_celsius()), temp);
+        prop_assert_eq!(Temperature::from_millicelsius(temp.as_millicelsius()), temp);
+    }
+
+    #[test]
+    fn encoding_rounds_to_nearest(celsius in -128.0..127.99f64) {
+        let raw = codec::encode_celsius(celsius, DataFormat::Normal).unwrap();
+        prop_assert!((codec::decode_celsius(raw, DataFormat::Normal) - celsius).abs() <= 0.5 / 256.0);
+    }
+
+    #[test]
+    fn unrepresentable_values_are_rejected(celsius in 128.0..1e6f64) {
+        prop_assert_eq!(codec::encode_celsius(celsius, DataFormat::Normal), None);
+        prop_assert_eq!(codec::encode_celsius(-celsius - 0.01, DataFormat::Normal), None);
+    }
+
+    #[test]
+    fn measurement_round_trips(raw: i16, format in format(), stale: bool, sequence: u16) {
+        let m = Measurement::new(raw, format, stale, sequence);
+        prop_assert_eq!(Measurement::from_bytes(m.to_bytes()), m);
+        prop_assert_eq!(m.temperature(), codec::decode(raw, format));
+    }
+}
+
+#[test]
+fn nan_is_rejected() {
+    assert_eq!(codec::encode_celsius(f64::NAN, DataFormat::Normal), None);
+}
+
+#[test]
+fn ties_round_away_from_zero() {
+    // 16 LSBs is exactly 62.5 m°C
+    assert_eq!(codec::decode_millicelsius(16, DataFormat::Normal), 63);
+    assert_eq!(codec::decode_millicelsius(-16, DataFormat::Normal), -63);
+    assert_eq!(Temperature::from_raw(-16).as_millicelsius(), -63);
+    assert_eq!(Temperature::from_celsius(-0.5 / 256.0).as_raw(), -1);
+    assert_eq!(codec::encode_celsius(0.5 / 256.0, DataFormat::Normal), Some(1));
+}
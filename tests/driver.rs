@@ -0,0 +1,405 @@
+mod common;
+
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+use common::eh1::{ack, init, nack, read_temp};
+use max30205::{MAX30205, MAX30205AutoShutdown, MAX30205Const, ADDRESSES, Access, Calibration, Config, ConstAddress, DataFormat, Error, NoDelay, OsMode, Register, Thresholds};
+
+const ADDR: u8 = 0x48;
+
+/// Runs `f` on a driver constructed with [`MAX30205::new`], expecting the given transactions after initialization.
+fn with_sensor(expected: &[Transaction], f: impl FnOnce(&mut MAX30205<Mock>)) {
+    let mut all = init(ADDR);
+    all.extend_from_slice(expected);
+    let mut mock = Mock::new(&all);
+    let mut sensor = MAX30205::new(ADDR, mock.clone()).unwrap();
+    f(&mut sensor);
+    mock.done();
+}
+
+#[test]
+fn new_initializes_device() {
+    with_sensor(&[], |_| ());
+}
+
+#[test]
+fn new_with_config_writes_config_and_thresholds() {
+    let config = Config { os_mode: OsMode::Interrupt, data_format: DataFormat::Extended, ..Config::default() };
+    let mut mock = Mock::new(&[
+        Transaction::write(ADDR, vec![1, 0x22]),
+        Transaction::write(ADDR, vec![2, 0xdb, 0x80]), // 27.5 °C is -36.5 °C relative to the extended offset
+        Transaction::write(ADDR, vec![3, 0xe0, 0x00]),
+    ]);
+    MAX30205::new_with_config(ADDR, mock.clone(), config, Thresholds { thyst: 27.5, tos: 32.0 }).unwrap();
+    mock.done();
+}
+
+#[test]
+fn new_with_config_rejects_thresholds_before_bus_access() {
+    let mut mock = Mock::new(&[]);
+    let res = MAX30205::new_with_config(ADDR, mock.clone(), Config::default(), Thresholds { thyst: 75.0, tos: 150.0 });
+    assert!(matches!(res, Err(Error::OutOfRange)));
+    mock.done();
+}
+
+#[test]
+fn scan_skips_absent_devices() {
+    let mut mock = Mock::new(&[nack(0x49), nack(0x48), ack(0x40)]);
+    assert_eq!(MAX30205::scan(&mut mock, &ADDRESSES), Ok(0x40));
+    mock.done();
+}
+
+#[test]
+fn scan_reports_no_device() {
+    let mut mock = Mock::new(&[nack(0x48), nack(0x49)]);
+    assert_eq!(MAX30205::scan(&mut mock, &[0x48, 0x49]), Err(Error::NoDevice));
+    mock.done();
+}
+
+#[test]
+fn scan_propagates_bus_faults() {
+    let mut mock = Mock::new(&[nack(0x48), Transaction::write(0x49, vec![]).with_error(ErrorKind::ArbitrationLoss)]);
+    assert_eq!(MAX30205::scan(&mut mock, &[0x48, 0x49, 0x4a]), Err(Error::Bus(ErrorKind::ArbitrationLoss)));
+    mock.done();
+}
+
+#[test]
+fn scan_all_finds_every_device() {
+    let mut mock = Mock::new(&[ack(0x48), nack(0x49), ack(0x4a)]);
+    assert_eq!(MAX30205::scan_all(&mut mock, &[0x48, 0x49, 0x4a]).unwrap().as_slice(), &[0x48, 0x4a]);
+    mock.done();
+}
+
+#[test]
+fn power_transitions_use_cached_config() {
+    with_sensor(&[
+        Transaction::write(ADDR, vec![1, 0x01]),
+        Transaction::write(ADDR, vec![1, 0x00]),
+    ], |sensor| {
+        sensor.power_down().unwrap();
+        sensor.power_up().unwrap();
+    });
+}
+
+#[test]
+fn attached_driver_reads_config_before_modifying() {
+    let mut mock = Mock::new(&[
+        Transaction::write_read(ADDR, vec![1], vec![0x3e]),
+        Transaction::write(ADDR, vec![1, 0x3f]),
+        Transaction::write(ADDR, vec![1, 0x3d]), // cached, so no second read
+    ]);
+    let mut sensor = MAX30205::attach(ADDR, mock.clone());
+    sensor.power_down().unwrap();
+    sensor.set_os_mode(OsMode::Comparator).unwrap();
+    mock.done();
+}
+
+#[test]
+fn one_shot_bit_is_not_cached() {
+    with_sensor(&[
+        Transaction::write(ADDR, vec![1, 0x01]),
+        Transaction::write(ADDR, vec![1, 0x81]),
+        Transaction::write(ADDR, vec![1, 0x03]),
+    ], |sensor| {
+        sensor.power_down().unwrap();
+        sensor.update_once().unwrap();
+        sensor.set_os_mode(OsMode::Interrupt).unwrap();
+    });
+}
+
+#[test]
+fn read_one_shot_converts_then_reads() {
+    with_sensor(&[
+        Transaction::write(ADDR, vec![1, 0x01]),
+        Transaction::write(ADDR, vec![1, 0x81]),
+        read_temp(ADDR, [0x24, 0x9a]),
+    ], |sensor| {
+        sensor.power_down().unwrap();
+        assert_eq!(sensor.read_one_shot(&mut NoDelay).unwrap().as_millicelsius(), 36_602);
+    });
+}
+
+#[test]
+fn one_shot_status_is_read_from_device() {
+    with_sensor(&[
+        Transaction::write_read(ADDR, vec![1], vec![0x81]),
+        Transaction::write_read(ADDR, vec![1], vec![0x01]),
+    ], |sensor| {
+        assert!(sensor.is_one_shot_pending().unwrap());
+        assert!(sensor.is_conversion_done().unwrap());
+    });
+}
+
+#[test]
+fn decodes_positive_temperatures() {
+    with_sensor(&[read_temp(ADDR, [0x25, 0x00]), read_temp(ADDR, [0x24, 0xd0]), read_temp(ADDR, [0x7f, 0xff])], |sensor| {
+        assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 37.0);
+        assert_eq!(sensor.get_temperature_millicelsius().unwrap(), 36_813);
+        assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 127.99609375);
+    });
+}
+
+#[test]
+fn decodes_negative_temperatures() {
+    with_sensor(&[read_temp(ADDR, [0xff, 0x80]), read_temp(ADDR, [0xff, 0xff]), read_temp(ADDR, [0x80, 0x00]), read_temp(ADDR, [0xf6, 0x40])], |sensor| {
+        assert_eq!(sensor.get_temperature().unwrap().as_celsius(), -0.5);
+        assert_eq!(sensor.get_temperature_millicelsius().unwrap(), -4);
+        assert_eq!(sensor.get_temperature_f32().unwrap(), -128.0);
+        assert_eq!(sensor.get_temperature().unwrap().as_raw(), -2496);
+    });
+}
+
+#[test]
+fn decodes_extended_format() {
+    with_sensor(&[
+        Transaction::write(ADDR, vec![1, 0x20]),
+        read_temp(ADDR, [0x00, 0x00]),
+        read_temp(ADDR, [0xc0, 0x00]),
+        read_temp(ADDR, [0xbf, 0x80]),
+    ], |sensor| {
+        sensor.set_data_format(DataFormat::Extended).unwrap();
+        assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 64.0);
+        assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 0.0);
+        assert_eq!(sensor.get_temperature_millicelsius().unwrap(), -500);
+    });
+}
+
+#[test]
+fn encodes_thresholds() {
+    with_sensor(&[
+        Transaction::write(ADDR, vec![3, 0x26, 0x80]),
+        Transaction::write(ADDR, vec![2, 0xfd, 0x00]),
+        Transaction::write_read(ADDR, vec![3], vec![0x26, 0x80]),
+    ], |sensor| {
+        sensor.set_tos(38.5).unwrap();
+        sensor.set_thyst(-3.0).unwrap();
+        assert_eq!(sensor.get_tos().unwrap().as_celsius(), 38.5);
+        assert!(matches!(sensor.set_tos(128.0), Err(Error::OutOfRange)));
+    });
+}
+
+#[test]
+fn register_errors_name_the_register() {
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data);
+    with_sensor(&[
+        Transaction::write_read(ADDR, vec![0], vec![0, 0]).with_error(nack),
+        Transaction::write(ADDR, vec![1, 0x01]).with_error(ErrorKind::Bus),
+    ], |sensor| {
+        assert_eq!(sensor.get_temperature(), Err(Error::Register { register: Register::Temp, access: Access::Read, error: nack }));
+        assert_eq!(sensor.power_down(), Err(Error::Register { register: Register::Config, access: Access::Write, error: ErrorKind::Bus }));
+        let stats = sensor.diagnostics();
+        assert_eq!((stats.errors, stats.nacks), (2, 1));
+    });
+}
+
+#[test]
+fn builder_scans_and_initializes() {
+    let mut expected = vec![nack(0x49), ack(ADDR)];
+    expected.extend(init(ADDR));
+    let mut mock = Mock::new(&expected);
+    let sensor = MAX30205::builder().build(mock.clone()).unwrap();
+    assert_eq!(sensor.address(), ADDR);
+    mock.done();
+}
+
+#[test]
+fn builder_preserves_existing_settings() {
+    let mut mock = Mock::new(&[
+        Transaction::write_read(ADDR, vec![1], vec![0x20]),
+        Transaction::write(ADDR, vec![2, 0xe0, 0x00]),
+        Transaction::write(ADDR, vec![3, 0xe2, 0x00]),
+        read_temp(ADDR, [0xe4, 0x80]),
+    ]);
+    let mut sensor = MAX30205::builder().address(ADDR).preserve_settings(true).thresholds(Thresholds { thyst: 32.0, tos: 34.0 }).build(mock.clone()).unwrap();
+    assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 36.5);
+    mock.done();
+}
+
+#[test]
+fn power_down_and_release_returns_bus() {
+    let mut expected = init(ADDR);
+    expected.push(Transaction::write(ADDR, vec![1, 0x01]));
+    let sensor = MAX30205::new(ADDR, Mock::new(&expected)).unwrap();
+    let Ok(mut bus) = sensor.power_down_and_release() else { panic!("power down failed") };
+    bus.done();
+}
+
+#[test]
+fn auto_shutdown_powers_down_on_drop() {
+    let mut expected = init(ADDR);
+    expected.extend([read_temp(ADDR, [0x25, 0x00]), Transaction::write(ADDR, vec![1, 0x01])]);
+    let mut mock = Mock::new(&expected);
+    {
+        let mut sensor = MAX30205AutoShutdown::new(MAX30205::new(ADDR, mock.clone()).unwrap());
+        assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 37.0);
+    }
+    mock.done();
+}
+
+#[test]
+fn auto_shutdown_into_inner_disarms() {
+    let mut mock = Mock::new(&init(ADDR));
+    let sensor = MAX30205AutoShutdown::new(MAX30205::new(ADDR, mock.clone()).unwrap());
+    drop(sensor.into_inner());
+    mock.done();
+}
+
+#[test]
+fn const_address_is_known_at_compile_time() {
+    const A: u8 = MAX30205Const::<Mock, 0x4a>::address();
+    assert_eq!(A, 0x4a);
+    let mut mock = Mock::new(&[]);
+    assert_eq!(MAX30205Const::<_, 0x4a>::attach(mock.clone()).address(), 0x4a); // through Deref
+    mock.done();
+}
+
+#[test]
+fn const_address_is_not_stored() {
+    assert_eq!(size_of::<ConstAddress<0x4a>>(), 0);
+
+    let mut expected = init(0x4a);
+    expected.extend([read_temp(0x4a, [0x25, 0x00]), read_temp(0x4a, [0x25, 0x80])]);
+    let mut mock = Mock::new(&expected);
+    let mut sensor = MAX30205Const::<_, 0x4a>::new(mock.clone()).unwrap();
+    assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 37.0);
+    let mut sensor = sensor.into_inner();
+    assert_eq!(sensor.address(), 0x4a);
+    assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 37.5);
+    mock.done();
+}
+
+#[test]
+fn auto_recovery_reinitializes_after_failures() {
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    with_sensor(&[
+        Transaction::write(ADDR, vec![1, 0x02]),
+        Transaction::write(ADDR, vec![3, 0x26, 0x00]),
+        read_temp(ADDR, [0, 0]).with_error(nack),
+        read_temp(ADDR, [0, 0]).with_error(nack),
+        // the most recently written configuration and thresholds are restored
+        Transaction::write(ADDR, vec![1, 0x02]),
+        Transaction::write(ADDR, vec![2, 0x00, 0x00]),
+        Transaction::write(ADDR, vec![3, 0x26, 0x00]),
+        read_temp(ADDR, [0x25, 0x00]),
+        read_temp(ADDR, [0, 0]).with_error(nack),
+    ], |sensor| {
+        sensor.set_auto_recovery(2);
+        sensor.set_os_mode(OsMode::Interrupt).unwrap();
+        sensor.set_tos(38.0).unwrap();
+        assert_eq!(sensor.get_temperature(), Err(Error::Register { register: Register::Temp, access: Access::Read, error: nack }));
+        assert_eq!(sensor.get_temperature(), Err(Error::Recovered { register: Register::Temp, access: Access::Read, error: nack }));
+        assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 37.0);
+        // the failure count restarts after recovery
+        assert_eq!(sensor.get_temperature(), Err(Error::Register { register: Register::Temp, access: Access::Read, error: nack }));
+
+        let stats = sensor.diagnostics();
+        assert_eq!((stats.transactions, stats.errors, stats.nacks, stats.recoveries), (9, 3, 3, 1));
+        sensor.reset_diagnostics();
+        assert_eq!(sensor.diagnostics(), Default::default());
+    });
+}
+
+#[test]
+fn auto_recovery_needs_a_written_config() {
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    let mut mock = Mock::new(&[read_temp(ADDR, [0, 0]).with_error(nack), read_temp(ADDR, [0, 0]).with_error(nack)]);
+    let mut sensor = MAX30205::attach(ADDR, mock.clone());
+    sensor.set_auto_recovery(1);
+    for _ in 0..2 {
+        assert_eq!(sensor.get_temperature(), Err(Error::Register { register: Register::Temp, access: Access::Read, error: nack }));
+    }
+    assert_eq!(sensor.diagnostics().recoveries, 0);
+    mock.done();
+}
+
+#[test]
+fn raw_reading_is_exact_register_value() {
+    with_sensor(&[read_temp(ADDR, [0x25, 0x00]), read_temp(ADDR, [0x25, 0x00]), read_temp(ADDR, [0x60, 0x00]), read_temp(ADDR, [0x25, 0x00])], |sensor| {
+        sensor.set_calibration_offset(0.5).unwrap();
+        sensor.set_plausible_range(Some((0.0, 50.0))).unwrap();
+        assert_eq!(sensor.get_temperature_raw().unwrap(), 0x2500);
+        assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 37.5);
+        assert_eq!(sensor.get_temperature_raw().unwrap(), 0x6000); // 96 °C is implausible, but still reported raw
+        assert_eq!(sensor.get_measurement(1).unwrap().raw, 0x2580);
+    });
+}
+
+#[test]
+fn calibrated_thresholds_round_trip() {
+    // a reading of t is corrected to 2t - 36, so 38 °C is programmed as a reading of 37 °C
+    with_sensor(&[
+        Transaction::write(ADDR, vec![3, 0x25, 0x00]),
+        Transaction::write_read(ADDR, vec![3], vec![0x25, 0x00]),
+        read_temp(ADDR, [0x25, 0x00]),
+        Transaction::write(ADDR, vec![1, 0x20]),
+        Transaction::write(ADDR, vec![3, 0xe5, 0x00]),
+        Transaction::write_read(ADDR, vec![3], vec![0xe5, 0x00]),
+        read_temp(ADDR, [0xe5, 0x00]),
+    ], |sensor| {
+        sensor.set_calibration(Calibration { gain: 2.0, offset: -36.0 }).unwrap();
+        sensor.set_tos(38.0).unwrap();
+        assert_eq!(sensor.get_tos().unwrap().as_celsius(), 38.0);
+        assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 38.0);
+
+        sensor.set_data_format(DataFormat::Extended).unwrap();
+        sensor.set_tos(38.0).unwrap();
+        assert_eq!(sensor.get_tos().unwrap().as_celsius(), 38.0);
+        assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 38.0);
+    });
+}
+
+#[test]
+fn calibration_rejects_unrepresentable_values() {
+    with_sensor(&[], |sensor| {
+        for gain in [0.0, -1.0, 32768.0, f64::NAN] {
+            assert!(matches!(sensor.set_calibration(Calibration { gain, offset: 0.0 }), Err(Error::OutOfRange)));
+        }
+        assert!(matches!(sensor.set_calibration_offset(128.0), Err(Error::OutOfRange)));
+        assert!(matches!(sensor.set_calibration_offset(f64::NAN), Err(Error::OutOfRange)));
+        assert_eq!(sensor.calibration(), Calibration::default());
+
+        sensor.set_calibration(Calibration { gain: 32767.0, offset: -128.0 }).unwrap();
+        assert_eq!(sensor.calibration(), Calibration { gain: 32767.0, offset: -128.0 });
+        // a shallow gain puts 100 °C at a reading of 200 °C, which is not representable
+        sensor.set_calibration(Calibration { gain: 0.5, offset: 0.0 }).unwrap();
+        assert!(matches!(sensor.set_tos(100.0), Err(Error::OutOfRange)));
+    });
+}
+
+#[test]
+fn plausible_range_rounds_bounds() {
+    with_sensor(&[read_temp(ADDR, [0x24, 0xff]), read_temp(ADDR, [0x25, 0x00]), read_temp(ADDR, [0xff, 0x00])], |sensor| {
+        // 36.999 °C is 9471.74 LSBs, which rounds up to 37.0 °C
+        sensor.set_plausible_range(Some((36.999, 37.5))).unwrap();
+        assert_eq!(sensor.get_temperature(), Err(Error::ImplausibleReading));
+        assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 37.0);
+        sensor.set_plausible_range(Some((-0.999, 0.0))).unwrap();
+        assert_eq!(sensor.get_temperature().unwrap().as_celsius(), -1.0);
+    });
+}
+
+#[test]
+fn plausible_range_rejects_invalid_bounds() {
+    with_sensor(&[read_temp(ADDR, [0x60, 0x00])], |sensor| {
+        sensor.set_plausible_range(Some((0.0, 50.0))).unwrap();
+        assert!(matches!(sensor.set_plausible_range(Some((f64::NAN, 50.0))), Err(Error::OutOfRange)));
+        assert!(matches!(sensor.set_plausible_range(Some((0.0, f64::NAN))), Err(Error::OutOfRange)));
+        assert!(matches!(sensor.set_plausible_range(Some((50.0, 0.0))), Err(Error::OutOfRange)));
+        // the previous range is still in effect
+        assert_eq!(sensor.get_temperature(), Err(Error::ImplausibleReading));
+    });
+}
+
+#[test]
+fn stuck_readings_are_rejected_on_request() {
+    with_sensor(&[read_temp(ADDR, [0x00, 0x00]), read_temp(ADDR, [0x00, 0x00]), read_temp(ADDR, [0xff, 0xff]), read_temp(ADDR, [0x00, 0x01])], |sensor| {
+        // the specified range alone accepts a shorted data line
+        sensor.set_plausible_range(Some(max30205::SPECIFIED_RANGE)).unwrap();
+        assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 0.0);
+        sensor.set_plausible_range(None).unwrap();
+        sensor.set_reject_stuck_readings(true);
+        assert_eq!(sensor.get_temperature(), Err(Error::ImplausibleReading));
+        assert_eq!(sensor.get_temperature(), Err(Error::ImplausibleReading));
+        assert_eq!(sensor.get_temperature().unwrap().as_raw(), 1);
+    });
+}
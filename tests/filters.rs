@@ -0,0 +1,62 @@
+#[cfg(feature = "kalman")]
+use max30205::filters::Kalman;
+use max30205::filters::{self, Exponential, Filter, Median, MovingAverage};
+use max30205::Temperature;
+
+fn celsius(c: f64) -> Temperature {
+    Temperature::from_celsius(c)
+}
+
+#[test]
+fn temperature_fn_wraps_filters() {
+    let mut f = filters::temperature_fn(MovingAverage::<2>::new());
+    assert_eq!(f(celsius(36.0)), celsius(36.0));
+    assert_eq!(f(celsius(37.0)), celsius(36.5));
+    assert_eq!(f(celsius(38.0)), celsius(37.5));
+}
+
+#[test]
+fn update_temperature_rounds_to_device_resolution() {
+    let mut median = Median::<3>::new();
+    for c in [36.0, 99.0, 36.25] {
+        median.update_temperature(celsius(c));
+    }
+    assert_eq!(median.update_temperature(celsius(36.5)), celsius(36.5)); // the spike is rejected
+
+    let mut exp = Exponential::new(32768);
+    exp.update_temperature(celsius(36.0));
+    assert_eq!(exp.update_temperature(celsius(36.01171875)).as_raw(), 9218); // 36.006 °C, rounded to the nearest LSB
+}
+
+#[cfg(feature = "kalman")]
+#[test]
+fn kalman_without_process_noise_averages() {
+    let mut kalman = Kalman::new(0, 100);
+    assert_eq!(kalman.value(), None);
+    assert_eq!(kalman.update(36_000), 36_000);
+    assert_eq!(kalman.variance(), Some(100));
+    assert_eq!(kalman.update(37_000), 36_500);
+    assert_eq!(kalman.variance(), Some(50));
+    assert_eq!(kalman.update(36_500), 36_500);
+    assert_eq!(kalman.variance(), Some(33));
+    // the gain is 33 / 133 rather than 1 / 4, since the variance is rounded down
+    assert_eq!(kalman.update(36_900), 36_599);
+    assert_eq!(kalman.variance(), Some(24));
+    kalman.reset();
+    assert_eq!(kalman.value(), None);
+}
+
+#[cfg(feature = "kalman")]
+#[test]
+fn kalman_fuses_by_noise() {
+    let mut kalman = Kalman::new(10, 100);
+    assert_eq!(kalman.correct(36_000, 100), 36_000);
+    kalman.predict();
+    assert_eq!(kalman.variance(), Some(110));
+    // equal noise meets in the middle, rounded to nearest
+    assert_eq!(kalman.correct(36_999, 110), 36_500);
+    assert_eq!(kalman.variance(), Some(55));
+    // a noiseless reading replaces the estimate
+    assert_eq!(kalman.correct(37_000, 0), 37_000);
+    assert_eq!(kalman.variance(), Some(0));
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "json")]
+
+use max30205::{DataFormat, Measurement};
+use serde_json_core::ser::Error;
+
+const EXPECTED: &str = r#"{"millicelsius":36809,"sequence":7,"stale":false}"#;
+
+fn measurement() -> Measurement {
+    Measurement::new(9423, DataFormat::Normal, false, 7)
+}
+
+#[test]
+fn serializes_readable_fields() {
+    let mut buf = [0; 64];
+    let len = measurement().to_json(&mut buf).unwrap();
+    assert_eq!(core::str::from_utf8(&buf[..len]).unwrap(), EXPECTED);
+}
+
+#[test]
+fn exact_buffer_fits() {
+    let mut buf = [0; EXPECTED.len()];
+    assert_eq!(measurement().to_json(&mut buf), Ok(EXPECTED.len()));
+    assert_eq!(&buf[..], EXPECTED.as_bytes());
+}
+
+#[test]
+fn short_buffer_is_full() {
+    let mut buf = [0; EXPECTED.len() - 1];
+    assert_eq!(measurement().to_json(&mut buf), Err(Error::BufferFull));
+    assert_eq!(measurement().to_json(&mut []), Err(Error::BufferFull));
+}
+
+#[test]
+fn longest_output_fits_documented_size() {
+    let m = Measurement::new(i16::MIN, DataFormat::Normal, false, u16::MAX);
+    let mut buf = [0; 55];
+    let len = m.to_json(&mut buf).unwrap();
+    assert_eq!(core::str::from_utf8(&buf[..len]).unwrap(), r#"{"millicelsius":-128000,"sequence":65535,"stale":false}"#);
+}
+
+#[test]
+fn extended_format_is_decoded() {
+    let m = Measurement::new(-64 * 256, DataFormat::Extended, false, 0);
+    let mut buf = [0; 64];
+    let len = m.to_json(&mut buf).unwrap();
+    assert_eq!(core::str::from_utf8(&buf[..len]).unwrap(), r#"{"millicelsius":0,"sequence":0,"stale":false}"#);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "embedded-hal-02")]
+
+mod common;
+
+use std::io;
+
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh0::i2c::Mock;
+use embedded_hal_mock::eh0::MockError;
+use common::eh0::{ack, init, nack, read_temp};
+use max30205::{MAX30205, ADDRESSES, Error, LegacyI2c, LegacyI2cError};
+
+#[test]
+fn scan_skips_absent_devices() {
+    let mut mock = Mock::new(&[nack(0x49), nack(0x48), ack(0x40)]);
+    let mut i2c = LegacyI2c::new(mock.clone());
+    assert_eq!(MAX30205::scan(&mut i2c, &ADDRESSES), Ok(0x40));
+    mock.done();
+}
+
+#[test]
+fn scan_all_finds_every_device() {
+    let mut mock = Mock::new(&[ack(0x48), nack(0x49), ack(0x4a)]);
+    let mut i2c = LegacyI2c::new(mock.clone());
+    assert_eq!(MAX30205::scan_all(&mut i2c, &[0x48, 0x49, 0x4a]).unwrap().as_slice(), &[0x48, 0x4a]);
+    mock.done();
+}
+
+#[test]
+fn errors_are_reported_as_nacks() {
+    let error = LegacyI2cError::Bus(MockError::Io(io::ErrorKind::Other));
+    assert_eq!(embedded_hal::i2c::Error::kind(&error), ErrorKind::NoAcknowledge(NoAcknowledgeSource::Unknown));
+    let unsupported = LegacyI2cError::<MockError>::Unsupported;
+    assert_eq!(embedded_hal::i2c::Error::kind(&unsupported), ErrorKind::Other);
+}
+
+#[test]
+fn reads_registers() {
+    let mut expected = init(0x48);
+    expected.push(read_temp(0x48, [0x25, 0x80]));
+    let mut mock = Mock::new(&expected);
+    let mut sensor = MAX30205::new(0x48, LegacyI2c::new(mock.clone())).unwrap();
+    assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 37.5);
+    let mut i2c = sensor.release();
+    assert_eq!(MAX30205::alert_response(&mut i2c), Err(Error::Bus(LegacyI2cError::Unsupported)));
+    mock.done();
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use embedded_hal::i2c::ErrorKind;
+use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+use common::eh1::{ack, nack};
+use max30205::lm75::{Core, LM75, TMP1075};
+use max30205::{Access, Error, Register, Temperature};
+
+#[test]
+fn truncates_to_part_resolution() {
+    let mut mock = Mock::new(&[
+        Transaction::write_read(0x48, vec![0], vec![0x19, 0xff]),
+        Transaction::write_read(0x49, vec![0], vec![0x19, 0xff]),
+    ]);
+    assert_eq!(Core::<_, LM75>::new(0x48, mock.clone()).temperature().unwrap(), Temperature::from_celsius(25.5));
+    assert_eq!(Core::<_, TMP1075>::new(0x49, mock.clone()).temperature().unwrap(), Temperature::from_celsius(25.9375));
+    mock.done();
+}
+
+#[test]
+fn writes_thresholds_and_config() {
+    let mut mock = Mock::new(&[
+        Transaction::write(0x48, vec![3, 0x50, 0x00]),
+        Transaction::write(0x48, vec![2, 0x4b, 0x00]),
+        Transaction::write_read(0x48, vec![1], vec![0x02]),
+        Transaction::write(0x48, vec![1, 0x03]),
+    ]);
+    let mut core = Core::<_, LM75>::new(0x48, mock.clone());
+    core.set_tos(Temperature::from_celsius(80.0)).unwrap();
+    core.set_thyst(Temperature::from_celsius(75.0)).unwrap();
+    let config = core.config().unwrap();
+    core.set_config(config | 0x01).unwrap();
+    assert!(matches!(core.write_register(Register::Tos, &[0, 0, 0]), Err(Error::OutOfRange)));
+    mock.done();
+}
+
+#[test]
+fn scans_part_addresses() {
+    let mut mock = Mock::new(&[nack(0x48), ack(0x49)]);
+    assert_eq!(Core::<_, LM75>::scan(&mut mock), Ok(0x49));
+    mock.done();
+}
+
+#[test]
+fn register_errors_name_the_register() {
+    let mut mock = Mock::new(&[Transaction::write_read(0x48, vec![2], vec![0, 0]).with_error(ErrorKind::Bus)]);
+    let mut core = Core::<_, LM75>::new(0x48, mock.clone());
+    assert_eq!(core.thyst(), Err(Error::Register { register: Register::Thyst, access: Access::Read, error: ErrorKind::Bus }));
+    mock.done();
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "embedded-hal-bus")]
+
+mod common;
+
+use core::cell::RefCell;
+
+use embedded_hal_bus::util::AtomicCell;
+use embedded_hal_mock::eh1::i2c::Mock;
+use common::eh1::{ack, init, nack, read_temp};
+use max30205::{MAX30205, Error};
+
+#[test]
+fn refcell_sensors_share_bus() {
+    let mut expected = Vec::new();
+    expected.extend(init(0x48));
+    expected.extend(init(0x49));
+    expected.extend([read_temp(0x48, [0x19, 0x00]), read_temp(0x49, [0x25, 0x80]), read_temp(0x48, [0x19, 0x40])]);
+    let mut mock = Mock::new(&expected);
+
+    let bus = RefCell::new(mock.clone());
+    let mut a = MAX30205::new_shared(0x48, &bus).unwrap();
+    let mut b = MAX30205::new_shared(0x49, &bus).unwrap();
+    assert_eq!(a.get_temperature().unwrap().as_celsius(), 25.0);
+    assert_eq!(b.get_temperature().unwrap().as_celsius(), 37.5);
+    assert_eq!(a.get_temperature().unwrap().as_celsius(), 25.25);
+
+    mock.done();
+}
+
+#[test]
+fn critical_section_device() {
+    let mut expected = init(0x4a);
+    expected.push(read_temp(0x4a, [0x24, 0xc0]));
+    let mut mock = Mock::new(&expected);
+
+    let bus = critical_section::Mutex::new(RefCell::new(mock.clone()));
+    let mut sensor = MAX30205::new_shared(0x4a, &bus).unwrap();
+    assert_eq!(sensor.get_temperature_millicelsius().unwrap(), 36_750);
+
+    mock.done();
+}
+
+#[test]
+fn atomic_device() {
+    let mut mock = Mock::new(&[read_temp(0x4b, [0x00, 0x80])]);
+
+    let bus = AtomicCell::new(mock.clone());
+    let mut sensor = MAX30205::attach_shared(0x4b, &bus);
+    assert_eq!(sensor.get_temperature().unwrap().as_celsius(), 0.5);
+
+    mock.done();
+}
+
+#[test]
+fn scan_shared_skips_absent_devices() {
+    let mut mock = Mock::new(&[nack(0x48), ack(0x49)]);
+
+    let bus = RefCell::new(mock.clone());
+    assert_eq!(MAX30205::scan_shared(&bus, &[0x48, 0x49, 0x4a]).unwrap(), 0x49);
+    assert!(bus.try_borrow_mut().is_ok()); // the bus is released after scanning
+
+    mock.done();
+}
+
+#[test]
+fn scan_shared_no_device() {
+    let mut mock = Mock::new(&[nack(0x48), nack(0x49)]);
+
+    let bus = RefCell::new(mock.clone());
+    assert_eq!(MAX30205::scan_shared(&bus, &[0x48, 0x49]), Err(Error::NoDevice));
+
+    mock.done();
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "sim")]
+
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+use max30205::{MAX30205, ADDRESSES, Access, DataFormat, Error, NoDelay, Register, SimulatedBus, SimulatedMAX30205, Temperature};
+
+fn celsius(c: f64) -> Temperature {
+    Temperature::from_celsius(c)
+}
+
+#[test]
+fn new_initializes_simulated_device() {
+    let mut sensor = SimulatedMAX30205::new(0x48, SimulatedBus::new(0x48, celsius(36.6))).unwrap();
+    assert_eq!(sensor.get_config().unwrap(), Default::default());
+    assert_eq!(sensor.get_thyst().unwrap(), celsius(0.0));
+    assert_eq!(sensor.get_tos().unwrap(), celsius(0.0));
+    assert_eq!(sensor.get_temperature().unwrap(), celsius(36.6));
+}
+
+#[test]
+fn continuous_reads_follow_profile() {
+    let profile = |n: u32| celsius(30.0 + n as f64 * 0.5);
+    let mut sensor = SimulatedMAX30205::new(0x48, SimulatedBus::new(0x48, profile)).unwrap();
+    for n in 0..4 {
+        assert_eq!(sensor.get_temperature().unwrap(), celsius(30.0 + n as f64 * 0.5));
+    }
+    assert_eq!(sensor.release().conversions(), 4);
+}
+
+#[test]
+fn one_shot_converts_exactly_once() {
+    let profile = |n: u32| celsius(20.0 + n as f64);
+    let mut sensor = SimulatedMAX30205::new(0x48, SimulatedBus::new(0x48, profile)).unwrap();
+    sensor.power_down().unwrap();
+    assert_eq!(sensor.read_one_shot(&mut NoDelay).unwrap(), celsius(20.0));
+    // the device stays shut down, so further reads return the same conversion
+    assert_eq!(sensor.get_temperature().unwrap(), celsius(20.0));
+    assert_eq!(sensor.read_one_shot(&mut NoDelay).unwrap(), celsius(21.0));
+    assert_eq!(sensor.release().conversions(), 2);
+}
+
+#[test]
+fn extended_format_saturates() {
+    let profile = |n: u32| if n == 0 { celsius(200.0) } else { celsius(-100.0) };
+    let mut sensor = SimulatedMAX30205::new(0x48, SimulatedBus::new(0x48, profile)).unwrap();
+    sensor.set_data_format(DataFormat::Extended).unwrap();
+    assert_eq!(sensor.get_temperature_raw().unwrap(), i16::MAX);
+    assert_eq!(sensor.get_temperature_raw().unwrap(), i16::MIN);
+    assert_eq!(sensor.get_temperature().unwrap(), celsius(-64.0));
+}
+
+#[test]
+fn noise_is_bounded_and_deterministic() {
+    let readings = |seed| {
+        let mut sensor = SimulatedMAX30205::new(0x48, SimulatedBus::new(0x48, celsius(37.0)).with_noise(8, seed)).unwrap();
+        core::array::from_fn::<_, 64, _>(|_| sensor.get_temperature().unwrap().as_raw() - celsius(37.0).as_raw())
+    };
+    let a = readings(1);
+    assert!(a.iter().all(|x| (-8..=8).contains(x)), "{a:?}");
+    assert!(a.iter().any(|&x| x != a[0]), "{a:?}");
+    assert_eq!(readings(1), a);
+    assert_ne!(readings(2), a);
+}
+
+#[test]
+fn other_addresses_are_not_acknowledged() {
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    let mut bus = SimulatedBus::new(0x4a, celsius(25.0));
+    assert_eq!(MAX30205::scan(&mut bus, &ADDRESSES), Ok(0x4a));
+    match SimulatedMAX30205::new(0x48, bus) {
+        Err(e) => assert_eq!(e, Error::Register { register: Register::Config, access: Access::Write, error: nack }),
+        Ok(_) => panic!("device at the wrong address was acknowledged"),
+    }
+}
@@ -0,0 +1,79 @@
+use max30205::stats::{RateOfChange, RunningStats, Summary, WindowStats};
+
+#[test]
+fn empty_has_no_summary() {
+    assert_eq!(RunningStats::new().summary(), None);
+    assert_eq!(WindowStats::<4>::new().summary(), None);
+}
+
+#[test]
+fn running_summary_is_exact() {
+    let mut stats = RunningStats::new();
+    for m in [36_000, 36_100, 36_200, 36_300] {
+        stats.update(m);
+    }
+    // deviations of ±50 and ±150 m°C give a variance of 12500 and a standard deviation of 111.8
+    assert_eq!(stats.summary(), Some(Summary { count: 4, mean: 36_150, variance: 12_500, std_dev: 112 }));
+}
+
+#[test]
+fn summary_rounds_away_from_zero() {
+    let mut stats = RunningStats::new();
+    stats.update(-1);
+    stats.update(-2);
+    // a mean of -1.5, a variance of 0.25, and a standard deviation of 0.5
+    assert_eq!(stats.summary(), Some(Summary { count: 2, mean: -2, variance: 0, std_dev: 1 }));
+}
+
+#[test]
+fn window_summary_covers_last_readings() {
+    let mut stats = WindowStats::<3>::new();
+    for m in [90_000, -90_000, 37_000, 37_000, 37_000] {
+        stats.update(m);
+    }
+    assert_eq!(stats.summary(), Some(Summary { count: 3, mean: 37_000, variance: 0, std_dev: 0 }));
+}
+
+#[test]
+fn extreme_readings_do_not_overflow() {
+    let mut stats = RunningStats::new();
+    stats.update(i32::MIN);
+    stats.update(i32::MAX);
+    let summary = stats.summary().unwrap();
+    assert_eq!(summary.mean, -1); // -0.5 rounded away from zero
+    assert_eq!(summary.std_dev, i32::MAX); // 2147483647.5, saturated rather than rounded past the maximum
+}
+
+#[test]
+fn rate_of_change_per_minute() {
+    let mut rate = RateOfChange::<3>::new(1000);
+    rate.update(0, 36_000);
+    assert_eq!(rate.rate(), None);
+    rate.update(30_000, 36_200);
+    rate.update(60_000, 36_500);
+    assert_eq!(rate.rate(), Some(500));
+    // the oldest reading is discarded
+    rate.update(90_000, 36_000);
+    assert_eq!(rate.rate(), Some(-200));
+
+    // timestamps may wrap
+    let mut rate = RateOfChange::<2>::new(1000);
+    rate.update(u32::MAX - 29_999, 36_000);
+    rate.update(30_000, 36_100);
+    assert_eq!(rate.rate(), Some(100));
+}
+
+#[test]
+fn rate_of_change_saturates() {
+    let mut rate = RateOfChange::<2>::new(u32::MAX);
+    rate.update(0, i32::MIN);
+    rate.update(1, i32::MAX);
+    assert_eq!(rate.rate(), Some(i32::MAX));
+    rate.update(2, i32::MIN);
+    assert_eq!(rate.rate(), Some(i32::MIN));
+
+    let mut rate = RateOfChange::<2>::new(1);
+    rate.update(0, i32::MIN);
+    rate.update(u32::MAX, i32::MAX); // about 136 years later
+    assert_eq!(rate.rate(), Some(60));
+}